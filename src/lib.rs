@@ -1,9 +1,20 @@
 #![no_std]
 
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::num::NonZero;
+use core::ops::Add;
+use core::ops::Bound;
 use core::ops::Deref;
 use core::ops::DerefMut;
 use core::ops::Index;
 use core::ops::IndexMut;
+use core::ops::Mul;
+use core::ops::Range;
+use core::ops::RangeBounds;
 use core::ptr;
 
 /// wrapper around `*mut [T]` that allows iterating over the values and indexing on pointers.
@@ -32,124 +43,3845 @@ impl<T> UncookedSlice<T> {
         UncookedSlice { inner: ptr }
     }
 
+    /// constructs a slice of `len` elements starting at `ptr`. this is the inverse of
+    /// [UncookedSlice::into_raw_parts].
+    ///
+    /// # Safety
+    ///
+    /// Same as [UncookedSlice::new].
+    pub const unsafe fn from_raw_parts(ptr: *mut T, len: usize) -> Self {
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, len),
+        }
+    }
+
+    /// like [UncookedSlice::new], but returns `None` if the slice spans more than
+    /// `max_bytes` bytes, or if its size in bytes doesn't fit in a `usize`.
+    ///
+    /// # Safety
+    ///
+    /// Same as [UncookedSlice::new].
+    pub unsafe fn new_within_bytes(ptr: *mut [T], max_bytes: usize) -> Option<Self> {
+        match ptr.len().checked_mul(size_of::<T>()) {
+            Some(bytes) if bytes <= max_bytes => Some(UncookedSlice { inner: ptr }),
+            _ => None,
+        }
+    }
+
+    /// constructs a slice covering the elements from `range.start` up to, but not
+    /// including, `range.end`. this is the inverse of [UncookedSlice::as_mut_ptr_range].
+    ///
+    /// # Safety
+    ///
+    /// Same as [UncookedSlice::new]. Additionally, `range.end` must not be before
+    /// `range.start`, and both must be derived from the same allocation, with the distance
+    /// between them an exact multiple of the size of `T`. See [pointer::offset_from_unsigned].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is zero-sized.
+    ///
+    /// [pointer::offset_from_unsigned]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset_from_unsigned
+    pub unsafe fn from_ptr_range(range: Range<*mut T>) -> Self {
+        let len = unsafe { range.end.offset_from_unsigned(range.start) };
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(range.start, len),
+        }
+    }
+
+    /// like [UncookedSlice::new], but with debug assertions that the pointer is non-null
+    /// and aligned for `T`. in release builds, this is identical to [UncookedSlice::new].
+    ///
+    /// # Safety
+    ///
+    /// Same as [UncookedSlice::new].
+    pub unsafe fn new_debug_checked(ptr: *mut [T]) -> Self {
+        let data = ptr.cast::<T>();
+        debug_assert!(!data.is_null(), "null pointer passed to UncookedSlice");
+        debug_assert!(
+            data.is_aligned(),
+            "misaligned pointer passed to UncookedSlice"
+        );
+        UncookedSlice { inner: ptr }
+    }
+
     pub const fn inner(self) -> *mut [T] {
         self.inner
     }
-}
 
-impl<T> Index<usize> for UncookedSlice<T> {
-    type Output = T;
-    fn index(&self, index: usize) -> &T {
-        unsafe { &*self.inner.cast::<T>().add(index) }
+    /// returns the pointer to the first element and the length of the slice. this is the
+    /// inverse of [UncookedSlice::from_raw_parts].
+    pub const fn into_raw_parts(self) -> (*mut T, usize) {
+        (self.inner.cast::<T>(), self.inner.len())
+    }
+
+    /// splits the slice into two at `mid` without checking that `mid` is in bounds.
+    /// the first half contains `[0, mid)` and the second half contains `[mid, len)`.
+    ///
+    /// # Safety
+    ///
+    /// `mid` must be less than or equal to the length of the slice. See
+    /// [slice::split_at_unchecked] for the equivalent on regular slices.
+    ///
+    /// [slice::split_at_unchecked]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_at_unchecked
+    pub unsafe fn split_at_unchecked(&self, mid: usize) -> (UncookedSlice<T>, UncookedSlice<T>) {
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<T>();
+
+        let left = ptr::slice_from_raw_parts_mut(ptr, mid);
+        let right = ptr::slice_from_raw_parts_mut(unsafe { ptr.add(mid) }, len - mid);
+
+        (
+            UncookedSlice { inner: left },
+            UncookedSlice { inner: right },
+        )
+    }
+
+    /// returns an iterator that yields the values by copy, without consuming or
+    /// advancing `self`.
+    pub fn iter_copied(&self) -> Copied<T>
+    where
+        T: Copy,
+    {
+        Copied { slice: *self }
+    }
+
+    /// returns an iterator over adjacent pairs of elements: `(0, 1)`, `(2, 3)`, and so on.
+    /// if the length is odd, the trailing element is not yielded.
+    pub fn pairs(&self) -> Pairs<'_, T> {
+        Pairs {
+            slice: UncookedSlice { inner: self.inner },
+            _marker: PhantomData,
+        }
+    }
+
+    /// calls `f` on every element of the slice, in order.
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        let ptr = self.inner.cast::<T>();
+        for i in 0..self.inner.len() {
+            f(unsafe { &mut *ptr.add(i) });
+        }
+    }
+
+    /// reads the value at `index` without creating a reference to it. see [ptr::read].
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds, and the value at `index` must be initialized. As with
+    /// [ptr::read], the value is bitwise copied, so reading a non-`Copy` value and then
+    /// using both copies may cause a double drop.
+    ///
+    /// [ptr::read]: https://doc.rust-lang.org/std/ptr/fn.read.html
+    pub unsafe fn read_at(&self, index: usize) -> T {
+        unsafe { ptr::read(self.inner.cast::<T>().add(index)) }
+    }
+
+    /// writes `value` at `index` without creating a reference to it, and without dropping
+    /// the previous value, which may be uninitialized. see [ptr::write].
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds.
+    ///
+    /// [ptr::write]: https://doc.rust-lang.org/std/ptr/fn.write.html
+    pub unsafe fn write_at(&mut self, index: usize, value: T) {
+        unsafe { ptr::write(self.inner.cast::<T>().add(index), value) }
+    }
+
+    /// reinterprets the slice as a slice of [MaybeUninit] values, keeping the length.
+    ///
+    /// note that writing uninitialized values through the returned slice makes it UB to
+    /// read them back through `self`.
+    pub const fn as_uninit(self) -> UncookedSlice<MaybeUninit<T>> {
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<MaybeUninit<T>>();
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, len),
+        }
+    }
+
+    /// returns a wrapper whose indexing operations are bounds-checked, panicking on
+    /// out-of-bounds indices instead of causing UB.
+    pub const fn checked(self) -> CheckedUncookedSlice<T> {
+        CheckedUncookedSlice { slice: self }
+    }
+
+    /// returns an iterator over the elements of the slice by reference, along with their
+    /// indices. unlike [Iterator::enumerate], this doesn't consume `self`.
+    ///
+    /// when `T: Copy`, `uncooked.enumerate()` resolves to [Iterator::enumerate] instead,
+    /// so call this as `UncookedSlice::enumerate(&uncooked)`.
+    pub fn enumerate(&self) -> Enumerate<'_, T> {
+        Enumerate {
+            slice: UncookedSlice { inner: self.inner },
+            index: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// returns an iterator over the mutable sub-slices separated by elements that match
+    /// `pred`. the matched elements are not contained in the sub-slices. see
+    /// [slice::split_mut].
+    ///
+    /// # Safety
+    ///
+    /// The yielded sub-slices never overlap each other, but they alias `self`. You must not
+    /// access an element through `self` (or any copy of it) while a reference obtained
+    /// through one of the yielded sub-slices is alive, and vice versa.
+    ///
+    /// [slice::split_mut]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_mut
+    pub unsafe fn split_mut<F: FnMut(&T) -> bool>(&mut self, pred: F) -> SplitMut<T, F> {
+        SplitMut {
+            slice: UncookedSlice { inner: self.inner },
+            pred,
+            finished: false,
+        }
+    }
+
+    /// copies the elements into an array if the length of the slice is exactly `N`,
+    /// otherwise returns `None`.
+    pub fn try_into_array<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Copy,
+    {
+        if self.inner.len() != N {
+            return None;
+        }
+        if N == 0 {
+            return Some(unsafe { ptr::read(ptr::dangling::<[T; N]>()) });
+        }
+
+        Some(unsafe { ptr::read(self.inner.cast::<[T; N]>()) })
+    }
+
+    /// returns an iterator over the sub-slices separated by elements that match `pred`,
+    /// starting from the end of the slice. see [slice::rsplit].
+    ///
+    /// [slice::rsplit]: https://doc.rust-lang.org/std/primitive.slice.html#method.rsplit
+    pub fn rsplit<F: FnMut(&T) -> bool>(&self, pred: F) -> RSplit<T, F> {
+        RSplit {
+            slice: UncookedSlice { inner: self.inner },
+            pred,
+            finished: false,
+        }
+    }
+
+    /// returns an iterator over at most `n` sub-slices separated by elements that match
+    /// `pred`. the last sub-slice contains the remainder of the slice, unsplit. see
+    /// [slice::splitn].
+    ///
+    /// [slice::splitn]: https://doc.rust-lang.org/std/primitive.slice.html#method.splitn
+    pub fn splitn<F: FnMut(&T) -> bool>(&self, n: usize, pred: F) -> SplitN<T, F> {
+        SplitN {
+            slice: UncookedSlice { inner: self.inner },
+            pred,
+            count: n,
+        }
+    }
+
+    /// returns a wrapper whose [Debug] implementation formats the elements of the slice as
+    /// a list, instead of the pointer like the derived [Debug] on [UncookedSlice] does.
+    ///
+    /// [Debug]: fmt::Debug
+    pub fn debug_contents(&self) -> impl fmt::Debug + '_
+    where
+        T: fmt::Debug,
+    {
+        DebugContents {
+            slice: UncookedSlice { inner: self.inner },
+            _marker: PhantomData,
+        }
+    }
+
+    /// copies all elements of `self` into `dest`, initializing it. the two slices may
+    /// overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn copy_to_uninit(&self, dest: &mut UncookedSlice<MaybeUninit<T>>)
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        let dest_len = dest.inner.len();
+        if len != dest_len {
+            panic!(
+                "source slice length ({len}) does not match destination slice length ({dest_len})"
+            );
+        }
+        if len == 0 {
+            return;
+        }
+
+        unsafe { ptr::copy(self.inner.cast::<T>(), dest.inner.cast::<T>(), len) }
+    }
+
+    /// returns a reference to the element `i` positions from the end, so `get_back(0)` is
+    /// the last element. returns `None` if `i` is out of bounds.
+    pub fn get_back(&self, i: usize) -> Option<&T> {
+        let len = self.inner.len();
+        if i >= len {
+            return None;
+        }
+        Some(unsafe { &*self.inner.cast::<T>().add(len - 1 - i) })
+    }
+
+    /// returns a reference to the element `i` positions from the end, so `index_back(0)`
+    /// is the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn index_back(&self, i: usize) -> &T {
+        let len = self.inner.len();
+        match self.get_back(i) {
+            Some(item) => item,
+            None => panic!("index {i} out of bounds for slice of len {len}"),
+        }
+    }
+
+    /// returns a mutable reference to the element `i` positions from the end, so
+    /// `index_back_mut(0)` is the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn index_back_mut(&mut self, i: usize) -> &mut T {
+        let len = self.inner.len();
+        if i >= len {
+            panic!("index {i} out of bounds for slice of len {len}");
+        }
+        unsafe { &mut *self.inner.cast::<T>().add(len - 1 - i) }
+    }
+
+    /// returns the sub-slice of up to `len` elements starting at `start`, clamped to the
+    /// bounds of `self`. the result is empty if `start` is past the end.
+    pub fn saturating_subslice(&self, start: usize, len: usize) -> UncookedSlice<T> {
+        let self_len = self.inner.len();
+        let start = start.min(self_len);
+        let end = start.saturating_add(len).min(self_len);
+
+        let ptr = unsafe { self.inner.cast::<T>().add(start) };
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, end - start),
+        }
+    }
+
+    /// returns the size of the slice in bytes.
+    pub const fn len_bytes(&self) -> usize {
+        self.inner.len() * size_of::<T>()
+    }
+
+    /// reinterprets the slice as a slice of `U`, returning `None` if the size of the slice
+    /// in bytes isn't an exact multiple of the size of `U`, or if the base pointer isn't
+    /// aligned for `U`. zero-sized `U` always returns `None`.
+    ///
+    /// # Safety
+    ///
+    /// Every `size_of::<U>()` bytes of `self` must be a valid value of `U`, and must stay
+    /// valid as `T` if they are written through the result and read back through `self`.
+    /// See [mem::transmute].
+    ///
+    /// [mem::transmute]: https://doc.rust-lang.org/std/mem/fn.transmute.html
+    pub unsafe fn checked_cast<U>(self) -> Option<UncookedSlice<U>> {
+        let bytes = self.len_bytes();
+        let ptr = self.inner.cast::<U>();
+
+        if size_of::<U>() == 0 || !bytes.is_multiple_of(size_of::<U>()) || !ptr.is_aligned() {
+            return None;
+        }
+
+        Some(UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, bytes / size_of::<U>()),
+        })
+    }
+
+    /// fills the elements in `range` with clones of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end` or `range.end > len`.
+    pub fn fill_range(&mut self, range: Range<usize>, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.inner.len();
+        let Range { start, end } = range;
+        if start > end {
+            panic!("slice index starts at {start} but ends at {end}");
+        }
+        if end > len {
+            panic!("range end index {end} out of range for slice of length {len}");
+        }
+
+        let ptr = self.inner.cast::<T>();
+        for i in start..end {
+            unsafe { *ptr.add(i) = value.clone() };
+        }
+    }
+
+    /// advances the start of the slice by `min(n, len)` elements in constant time. returns
+    /// `Err(k)` if the slice had `k` fewer than `n` elements, in which case it is left
+    /// empty. see [Iterator::advance_by].
+    ///
+    /// [Iterator::advance_by] is unstable, so this is an inherent method instead of an
+    /// override, and is used by the [Iterator::nth] override.
+    ///
+    /// [Iterator::advance_by]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.advance_by
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+        let old_len = self.inner.len();
+        let step = n.min(old_len);
+
+        let new_ptr = unsafe { self.inner.cast::<T>().add(step) };
+        self.inner = ptr::slice_from_raw_parts_mut(new_ptr, old_len - step);
+
+        match NonZero::new(n - step) {
+            None => Ok(()),
+            Some(remaining) => Err(remaining),
+        }
+    }
+
+    /// returns an iterator over non-overlapping mutable chunks of `chunk_size` elements,
+    /// starting at the beginning of the slice. the last chunk is shorter if `chunk_size`
+    /// doesn't divide the length. see [slice::chunks_mut].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// [slice::chunks_mut]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunks_mut
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        ChunksMut {
+            slice: UncookedSlice { inner: self.inner },
+            chunk_size,
+        }
+    }
+
+    /// scatters the interleaved elements of `self` into `channels`, so that element `i`
+    /// is written to `channels[i % channels.len()][i / channels.len()]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is empty, or if the channels don't all have a length of
+    /// exactly `len / channels.len()` with no remainder.
+    pub fn deinterleave_into(&self, channels: &mut [UncookedSlice<T>])
+    where
+        T: Copy,
+    {
+        let frames = self.channel_len(channels);
+        let count = channels.len();
+        let src = self.inner.cast::<T>();
+
+        for (c, channel) in channels.iter_mut().enumerate() {
+            let dest = channel.inner.cast::<T>();
+            for frame in 0..frames {
+                unsafe { *dest.add(frame) = *src.add(frame * count + c) };
+            }
+        }
+    }
+
+    /// gathers the elements of `channels` into `self`, interleaving them so that
+    /// `channels[i % channels.len()][i / channels.len()]` is written to element `i`. this
+    /// is the inverse of [UncookedSlice::deinterleave_into].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is empty, or if the channels don't all have a length of
+    /// exactly `len / channels.len()` with no remainder.
+    pub fn interleave_from(&mut self, channels: &[UncookedSlice<T>])
+    where
+        T: Copy,
+    {
+        let frames = self.channel_len(channels);
+        let count = channels.len();
+        let dest = self.inner.cast::<T>();
+
+        for (c, channel) in channels.iter().enumerate() {
+            let src = channel.inner.cast::<T>();
+            for frame in 0..frames {
+                unsafe { *dest.add(frame * count + c) = *src.add(frame) };
+            }
+        }
+    }
+
+    fn channel_len(&self, channels: &[UncookedSlice<T>]) -> usize {
+        let len = self.inner.len();
+        let count = channels.len();
+        assert!(count != 0, "channel count must be non-zero");
+
+        let frames = len / count;
+        let matches = channels.iter().all(|channel| channel.inner.len() == frames);
+        assert!(
+            matches && frames * count == len,
+            "channels of len {frames} don't add up to slice of len {len}"
+        );
+
+        frames
+    }
+
+    /// returns the index of the element that `ptr` points to, or `None` if `ptr` doesn't
+    /// point to the start of an element of the slice. always returns `None` for zero-sized
+    /// `T`, since every element has the same address.
+    ///
+    /// only the addresses are compared, so `ptr` doesn't need to be derived from `self`.
+    pub fn position_of_ptr(&self, ptr: *const T) -> Option<usize> {
+        let size = size_of::<T>();
+        if size == 0 {
+            return None;
+        }
+
+        let offset = ptr.addr().wrapping_sub(self.inner.addr());
+        if offset >= self.len_bytes() || !offset.is_multiple_of(size) {
+            return None;
+        }
+
+        Some(offset / size)
+    }
+
+    /// returns a reference to the first `N` elements as an array along with the rest of
+    /// the slice. if the slice is shorter than `N`, returns `None` and the whole slice.
+    pub fn split_array<const N: usize>(&self) -> (Option<&[T; N]>, UncookedSlice<T>) {
+        let len = self.inner.len();
+        if len < N {
+            return (None, UncookedSlice { inner: self.inner });
+        }
+        if N == 0 {
+            return (
+                Some(unsafe { &*ptr::dangling::<[T; N]>() }),
+                UncookedSlice { inner: self.inner },
+            );
+        }
+
+        let ptr = self.inner.cast::<T>();
+        let rest = ptr::slice_from_raw_parts_mut(unsafe { ptr.add(N) }, len - N);
+
+        (
+            Some(unsafe { &*ptr.cast::<[T; N]>() }),
+            UncookedSlice { inner: rest },
+        )
+    }
+
+    /// copies all elements from `src` into `self`.
+    ///
+    /// # Safety
+    ///
+    /// The two slices must not overlap. See [ptr::copy_nonoverlapping].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    ///
+    /// [ptr::copy_nonoverlapping]: https://doc.rust-lang.org/std/ptr/fn.copy_nonoverlapping.html
+    pub unsafe fn copy_from(&mut self, src: &UncookedSlice<T>)
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        let src_len = src.inner.len();
+        if len != src_len {
+            panic!(
+                "source slice length ({src_len}) does not match destination slice length ({len})"
+            );
+        }
+
+        unsafe { ptr::copy_nonoverlapping(src.inner.cast::<T>(), self.inner.cast::<T>(), len) }
+    }
+
+    /// reconstructs a shared slice reference with an arbitrary lifetime `'a`, after
+    /// cross-checking `len` against the length stored in the pointer metadata.
+    ///
+    /// this is meant to be the single auditable place where an [UncookedSlice] turns
+    /// back into a `&[T]`. passing the expected length forces the call site to state what
+    /// it believes the slice looks like, and a wrong belief panics instead of silently
+    /// producing a reference of the wrong size.
+    ///
+    /// # Safety
+    ///
+    /// For the whole lifetime `'a`:
+    ///
+    /// - the pointer must be valid for reads of `len` elements, non-null, and aligned,
+    ///   even if `len` is zero. See [slice::from_raw_parts].
+    /// - every element must be initialized.
+    /// - the elements must not be mutated through `self`, any copy of it, or any other
+    ///   pointer, except inside an `UnsafeCell`.
+    ///
+    /// Nothing ties `'a` to the actual allocation, so the caller is responsible for picking
+    /// a lifetime that doesn't outlive it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` doesn't equal the length of the slice.
+    ///
+    /// [slice::from_raw_parts]: https://doc.rust-lang.org/std/slice/fn.from_raw_parts.html
+    pub unsafe fn borrow_for<'a>(self, len: usize) -> &'a [T] {
+        let actual = self.inner.len();
+        if len != actual {
+            panic!("expected slice of len {len}, but the slice has len {actual}");
+        }
+
+        unsafe { &*self.inner }
+    }
+
+    /// returns references to the minimum and maximum elements, found in a single pass, or
+    /// `None` if the slice is empty. like [Iterator::min] and [Iterator::max], the first
+    /// minimum and the last maximum are returned when there are several.
+    pub fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord,
+    {
+        let len = self.inner.len();
+        if len == 0 {
+            return None;
+        }
+
+        let ptr = self.inner.cast::<T>();
+        let first = unsafe { &*ptr };
+        let (mut min, mut max) = (first, first);
+
+        for i in 1..len {
+            let item = unsafe { &*ptr.add(i) };
+            if item < min {
+                min = item;
+            } else if item >= max {
+                max = item;
+            }
+        }
+
+        Some((min, max))
+    }
+
+    /// rotates the slice in place so that the element at `index` becomes the first
+    /// element, and the elements before it wrap around to the back. equivalent to
+    /// [slice::rotate_left].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// [slice::rotate_left]: https://doc.rust-lang.org/std/primitive.slice.html#method.rotate_left
+    pub fn rotate_to_front(&mut self, index: usize) {
+        let len = self.inner.len();
+        if index > len {
+            panic!("index {index} out of bounds for slice of len {len}");
+        }
+        if len == 0 {
+            return;
+        }
+
+        unsafe { (*self.inner).rotate_left(index) }
+    }
+
+    /// returns an iterator over the leading elements that match `pred`. the iterator stops
+    /// for good at the first element that doesn't match, and [IterWhile::remainder]
+    /// returns the elements that haven't been yielded.
+    pub fn iter_while<P: FnMut(&T) -> bool>(&self, pred: P) -> IterWhile<'_, T, P> {
+        IterWhile {
+            slice: UncookedSlice { inner: self.inner },
+            pred,
+            finished: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// returns the sub-slice covered by `range`, which can be any kind of range over
+    /// `usize`, such as `1..4`, `..=2`, `3..` or `..`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, if the end is greater
+    /// than `len`, or if an inclusive bound is `usize::MAX`.
+    pub fn slice_range<R: RangeBounds<usize>>(&self, range: R) -> UncookedSlice<T> {
+        let len = self.inner.len();
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => match start.checked_add(1) {
+                Some(start) => start,
+                None => panic!("attempted to index slice from after maximum usize"),
+            },
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => match end.checked_add(1) {
+                Some(end) => end,
+                None => panic!("attempted to index slice up to maximum usize"),
+            },
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        if start > end {
+            panic!("slice index starts at {start} but ends at {end}");
+        }
+        if end > len {
+            panic!("range end index {end} out of range for slice of length {len}");
+        }
+
+        let ptr = unsafe { self.inner.cast::<T>().add(start) };
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, end - start),
+        }
+    }
+
+    /// splits the slice into two at `mid`, or returns `None` if `mid > len`. see
+    /// [slice::split_at_checked].
+    ///
+    /// [slice::split_at_checked]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_at_checked
+    pub fn split_at_checked(&self, mid: usize) -> Option<(UncookedSlice<T>, UncookedSlice<T>)> {
+        if mid > self.inner.len() {
+            return None;
+        }
+
+        Some(unsafe { self.split_at_unchecked(mid) })
+    }
+
+    /// returns the first `min(n, len)` elements, and advances `self` past them.
+    pub fn split_off_front(&mut self, n: usize) -> UncookedSlice<T> {
+        let (front, rest) = unsafe { self.split_at_unchecked(n.min(self.inner.len())) };
+        self.inner = rest.inner;
+        front
+    }
+
+    /// writes the elements of `iter` into successive slots of the slice until either the
+    /// iterator runs out or the slice is full, and returns the number of elements written.
+    /// no more elements than fit are pulled from the iterator.
+    ///
+    /// the slots are written with [ptr::write], so the previous values are not dropped.
+    /// this makes it usable on uninitialized memory, but leaks the previous values if they
+    /// needed dropping.
+    ///
+    /// [ptr::write]: https://doc.rust-lang.org/std/ptr/fn.write.html
+    pub fn write_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<T>();
+        let mut iter = iter.into_iter();
+
+        let mut written = 0;
+        while written < len {
+            let Some(value) = iter.next() else {
+                break;
+            };
+            unsafe { ptr::write(ptr.add(written), value) };
+            written += 1;
+        }
+
+        written
+    }
+
+    /// returns the number of leading elements to skip for the base pointer to be aligned to
+    /// `align` bytes, or `usize::MAX` if that isn't possible. see [pointer::align_offset].
+    ///
+    /// the result can be larger than the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// [pointer::align_offset]: https://doc.rust-lang.org/std/primitive.pointer.html#method.align_offset
+    pub fn align_offset(&self, align: usize) -> usize {
+        self.inner.cast::<T>().align_offset(align)
+    }
+
+    /// reverses the order of the `chunk_size`-element chunks of the slice, keeping the
+    /// order of the elements inside each chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero or doesn't divide the length of the slice.
+    pub fn reverse_chunks(&mut self, chunk_size: usize)
+    where
+        T: Copy,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let len = self.inner.len();
+        assert!(
+            len.is_multiple_of(chunk_size),
+            "chunk size {chunk_size} doesn't divide slice of len {len}"
+        );
+
+        let ptr = self.inner.cast::<T>();
+        let chunks = len / chunk_size;
+        for i in 0..chunks / 2 {
+            let front = unsafe { ptr.add(i * chunk_size) };
+            let back = unsafe { ptr.add((chunks - 1 - i) * chunk_size) };
+            unsafe { ptr::swap_nonoverlapping(front, back, chunk_size) };
+        }
+    }
+
+    /// replaces every element with the sum of itself and all the elements before it.
+    pub fn prefix_sum_in_place(&mut self)
+    where
+        T: Copy + Add<Output = T>,
+    {
+        let len = self.inner.len();
+        if len == 0 {
+            return;
+        }
+
+        let ptr = self.inner.cast::<T>();
+        let mut sum = unsafe { *ptr };
+        for i in 1..len {
+            unsafe {
+                sum = sum + *ptr.add(i);
+                *ptr.add(i) = sum;
+            }
+        }
+    }
+
+    /// returns the number of chunks of `chunk_size` elements the slice splits into, counting
+    /// a shorter last chunk. returns 0 if `chunk_size` is zero.
+    pub const fn chunk_count(&self, chunk_size: usize) -> usize {
+        if chunk_size == 0 {
+            return 0;
+        }
+        self.inner.len().div_ceil(chunk_size)
+    }
+
+    /// returns the number of overlapping windows of `size` elements in the slice. returns
+    /// 0 if `size` is zero or greater than the length.
+    pub const fn window_count(&self, size: usize) -> usize {
+        if size == 0 {
+            return 0;
+        }
+        self.inner.len().saturating_sub(size - 1)
+    }
+
+    /// returns the half-open range of pointers from the first element to one past the last
+    /// element. see [slice::as_mut_ptr_range].
+    ///
+    /// [slice::as_mut_ptr_range]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_mut_ptr_range
+    pub fn as_mut_ptr_range(&self) -> Range<*mut T> {
+        let start = self.inner.cast::<T>();
+        let end = unsafe { start.add(self.inner.len()) };
+        start..end
+    }
+
+    /// swaps the first `n` elements with the last `n` elements, keeping the order inside
+    /// each group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2 * n > len`, since the two groups would overlap.
+    pub fn swap_ends(&mut self, n: usize) {
+        let len = self.inner.len();
+        if n > len / 2 {
+            panic!("cannot swap the first and last {n} elements of slice of len {len}");
+        }
+        if n == 0 {
+            return;
+        }
+
+        let ptr = self.inner.cast::<T>();
+        unsafe { ptr::swap_nonoverlapping(ptr, ptr.add(len - n), n) };
+    }
+
+    /// treats `self` as a row-major matrix of `rows` by `cols` elements and writes its
+    /// transpose into `dest`, so that `self[r * cols + c]` ends up in `dest[c * rows + r]`.
+    ///
+    /// the two slices must not overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows * cols` isn't the length of `self`, or if `dest` has a different
+    /// length than `self`.
+    pub fn transpose_into(&self, dest: &mut UncookedSlice<T>, rows: usize, cols: usize)
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        assert!(
+            rows.checked_mul(cols) == Some(len),
+            "{rows}x{cols} matrix doesn't match slice of len {len}"
+        );
+        let dest_len = dest.inner.len();
+        if len != dest_len {
+            panic!(
+                "source slice length ({len}) does not match destination slice length ({dest_len})"
+            );
+        }
+
+        let src = self.inner.cast::<T>();
+        let dst = dest.inner.cast::<T>();
+        for r in 0..rows {
+            for c in 0..cols {
+                unsafe { *dst.add(c * rows + r) = *src.add(r * cols + c) };
+            }
+        }
+    }
+
+    /// returns an iterator that yields the values by copy from last to first, without
+    /// consuming or shrinking `self`.
+    pub fn iter_rev_copied(&self) -> RevCopied<T>
+    where
+        T: Copy,
+    {
+        RevCopied { slice: *self }
+    }
+
+    /// writes `f(i)` into every slot `i`, in order. like [UncookedSlice::write_iter], the
+    /// old values are overwritten without being dropped.
+    pub fn fill_indexed<F: FnMut(usize) -> T>(&mut self, mut f: F) {
+        let ptr = self.inner.cast::<T>();
+        for i in 0..self.inner.len() {
+            unsafe { ptr::write(ptr.add(i), f(i)) };
+        }
+    }
+
+    /// returns the sub-slice covered by `range`, or `None` if the range is out of bounds
+    /// or its start is greater than its end. this is the non-panicking version of
+    /// [UncookedSlice::slice_range].
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<UncookedSlice<T>> {
+        let len = self.inner.len();
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        if start > end || end > len {
+            return None;
+        }
+
+        let ptr = unsafe { self.inner.cast::<T>().add(start) };
+        Some(UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, end - start),
+        })
+    }
+
+    /// returns the dot product of `self` and `other`: the sum of the products of their
+    /// corresponding elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn dot<S>(&self, other: &UncookedSlice<T>) -> S
+    where
+        T: Copy + Mul<Output = T>,
+        S: Sum<T>,
+    {
+        let len = self.inner.len();
+        let other_len = other.inner.len();
+        if len != other_len {
+            panic!("slice lengths ({len} and {other_len}) do not match");
+        }
+
+        let a = self.inner.cast::<T>();
+        let b = other.inner.cast::<T>();
+        (0..len).map(|i| unsafe { *a.add(i) * *b.add(i) }).sum()
+    }
+
+    /// moves the elements `n` slots towards the front, and fills the `n` vacated slots at
+    /// the back with `fill`. if `n >= len`, the whole slice is filled.
+    pub fn shift_left(&mut self, n: usize, fill: T)
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        if len == 0 {
+            return;
+        }
+
+        let n = n.min(len);
+        let ptr = self.inner.cast::<T>();
+
+        unsafe { ptr::copy(ptr.add(n), ptr, len - n) };
+        for i in len - n..len {
+            unsafe { *ptr.add(i) = fill };
+        }
+    }
+
+    /// moves the elements `n` slots towards the back, and fills the `n` vacated slots at
+    /// the front with `fill`. if `n >= len`, the whole slice is filled.
+    pub fn shift_right(&mut self, n: usize, fill: T)
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        if len == 0 {
+            return;
+        }
+
+        let n = n.min(len);
+        let ptr = self.inner.cast::<T>();
+
+        unsafe { ptr::copy(ptr, ptr.add(n), len - n) };
+        for i in 0..n {
+            unsafe { *ptr.add(i) = fill };
+        }
+    }
+
+    /// reorders the slice so that all elements matching `pred` come before all elements
+    /// that don't, and returns the number of matching elements.
+    ///
+    /// the partition is not stable: elements may not keep their relative order within
+    /// either group. `pred` is called exactly once per element.
+    pub fn partition_copied<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize
+    where
+        T: Copy,
+    {
+        let ptr = self.inner.cast::<T>();
+        let mut front = 0;
+        let mut back = self.inner.len();
+
+        loop {
+            while front < back && pred(unsafe { &*ptr.add(front) }) {
+                front += 1;
+            }
+
+            // the element at `front` doesn't match, find one from the back that does.
+            loop {
+                if front == back {
+                    return front;
+                }
+                back -= 1;
+                if front != back && pred(unsafe { &*ptr.add(back) }) {
+                    break;
+                }
+            }
+
+            unsafe { ptr::swap(ptr.add(front), ptr.add(back)) };
+            front += 1;
+        }
+    }
+
+    /// folds the elements into one, using the first element as the initial accumulator, or
+    /// returns `None` if the slice is empty. see [Iterator::reduce].
+    ///
+    /// `uncooked.reduce(f)` resolves to [Iterator::reduce] instead, so call this as
+    /// `UncookedSlice::reduce(&uncooked, f)`.
+    pub fn reduce<F: FnMut(T, &T) -> T>(&self, mut f: F) -> Option<T>
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        if len == 0 {
+            return None;
+        }
+
+        let ptr = self.inner.cast::<T>();
+        let mut acc = unsafe { *ptr };
+        for i in 1..len {
+            acc = f(acc, unsafe { &*ptr.add(i) });
+        }
+
+        Some(acc)
+    }
+
+    /// returns the index of the first occurrence of `needle` as a contiguous run of
+    /// elements, or `None` if there is none. an empty `needle` is found at index 0.
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let len = self.inner.len();
+        if needle.len() > len {
+            return None;
+        }
+
+        let ptr = self.inner.cast::<T>();
+        (0..=len - needle.len()).find(|&start| {
+            needle
+                .iter()
+                .enumerate()
+                .all(|(i, item)| unsafe { *ptr.add(start + i) == *item })
+        })
+    }
+
+    /// returns a slice with the same base pointer but a length of `new_len`. see
+    /// [Vec::set_len].
+    ///
+    /// # Safety
+    ///
+    /// Same as [UncookedSlice::new] for the resulting slice: the `new_len` elements starting
+    /// at the base pointer must be part of the same allocation, and initialized.
+    ///
+    /// [Vec::set_len]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.set_len
+    pub const unsafe fn with_len(self, new_len: usize) -> UncookedSlice<T> {
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(self.inner.cast::<T>(), new_len),
+        }
+    }
+
+    /// splits the slice into two halves of equal length, or returns `None` if the length is
+    /// odd. the halves don't overlap, so both can be written to.
+    pub fn split_in_half_mut(&mut self) -> Option<(UncookedSlice<T>, UncookedSlice<T>)> {
+        let len = self.inner.len();
+        if !len.is_multiple_of(2) {
+            return None;
+        }
+
+        Some(unsafe { self.split_at_unchecked(len / 2) })
+    }
+
+    /// returns a reference to the element at `index`, or to the last element if `index` is
+    /// out of bounds. returns `None` only if the slice is empty.
+    pub fn saturating_index(&self, index: usize) -> Option<&T> {
+        let len = self.inner.len();
+        if len == 0 {
+            return None;
+        }
+        Some(unsafe { &*self.inner.cast::<T>().add(index.min(len - 1)) })
+    }
+
+    /// returns the number of elements that match `pred`.
+    pub fn count_matching<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        let ptr = self.inner.cast::<T>();
+        (0..self.inner.len())
+            .filter(|&i| pred(unsafe { &*ptr.add(i) }))
+            .count()
+    }
+
+    /// copies `self[start]`, `self[start + stride]`, `self[start + 2 * stride]`, and so
+    /// on, into successive slots of `dest` until `dest` is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero, or if `self` runs out of elements before `dest` is full.
+    /// nothing is written in that case.
+    pub fn copy_strided_into(&self, dest: &mut UncookedSlice<T>, start: usize, stride: usize)
+    where
+        T: Copy,
+    {
+        assert!(stride != 0, "stride must be non-zero");
+
+        let len = self.inner.len();
+        let count = dest.inner.len();
+        if count == 0 {
+            return;
+        }
+
+        let last = (count - 1)
+            .checked_mul(stride)
+            .and_then(|offset| offset.checked_add(start));
+        match last {
+            Some(last) if last < len => {}
+            _ => panic!(
+                "slice of len {len} is too short to gather {count} elements from {start} with stride {stride}"
+            ),
+        }
+
+        let src = self.inner.cast::<T>();
+        let dst = dest.inner.cast::<T>();
+        for i in 0..count {
+            unsafe { *dst.add(i) = *src.add(start + i * stride) };
+        }
+    }
+
+    /// returns the range of indices of the elements equal to `x` in a sorted slice. if
+    /// there are none, the range is empty and starts at the index where `x` could be
+    /// inserted while keeping the slice sorted.
+    ///
+    /// the result is unspecified if the slice isn't sorted.
+    pub fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        if self.inner.is_empty() {
+            return 0..0;
+        }
+
+        let slice = unsafe { &*self.inner };
+        let lower = slice.partition_point(|item| item < x);
+        let upper = lower + slice[lower..].partition_point(|item| item <= x);
+        lower..upper
+    }
+
+    /// sorts the slice in place without allocating. the sort is not stable: equal elements
+    /// may be reordered. see [slice::sort_unstable].
+    ///
+    /// [slice::sort_unstable]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// sorts the slice in place with the comparator `compare`, without allocating. the sort
+    /// is not stable: equal elements may be reordered. see [slice::sort_unstable_by].
+    ///
+    /// [slice::sort_unstable_by]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let len = self.inner.len();
+        let limit = 2 * (usize::BITS - len.leading_zeros());
+        let mut is_less = |a: &T, b: &T| compare(a, b) == Ordering::Less;
+        unsafe { Self::introsort(self.inner.cast::<T>(), len, &mut is_less, limit) };
+    }
+
+    /// quicksorts the `len` elements at `ptr`, falling back to heapsort once `limit` levels
+    /// of partitioning have been used up, and to insertion sort for short runs. elements
+    /// are only ever moved with [ptr::swap], so a panicking `is_less` leaves every element
+    /// in the slice exactly once.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `len` elements if `len > 1`.
+    ///
+    /// [ptr::swap]: https://doc.rust-lang.org/std/ptr/fn.swap.html
+    unsafe fn introsort<F: FnMut(&T, &T) -> bool>(
+        mut ptr: *mut T,
+        mut len: usize,
+        is_less: &mut F,
+        mut limit: u32,
+    ) {
+        let less = |is_less: &mut F, i: usize, j: usize, ptr: *mut T| unsafe {
+            is_less(&*ptr.add(i), &*ptr.add(j))
+        };
+
+        loop {
+            if len <= 16 {
+                for i in 1..len {
+                    let mut j = i;
+                    while j > 0 && less(is_less, j, j - 1, ptr) {
+                        unsafe { ptr::swap(ptr.add(j), ptr.add(j - 1)) };
+                        j -= 1;
+                    }
+                }
+                return;
+            }
+
+            if limit == 0 {
+                unsafe { Self::heapsort(ptr, len, is_less) };
+                return;
+            }
+            limit -= 1;
+
+            // move the median of the first, middle and last elements to the back and use it
+            // as the pivot.
+            let (a, b, c) = (0, len / 2, len - 1);
+            if less(is_less, b, a, ptr) {
+                unsafe { ptr::swap(ptr.add(a), ptr.add(b)) };
+            }
+            if less(is_less, c, b, ptr) {
+                unsafe { ptr::swap(ptr.add(b), ptr.add(c)) };
+            }
+            if less(is_less, b, a, ptr) {
+                unsafe { ptr::swap(ptr.add(a), ptr.add(b)) };
+            }
+            unsafe { ptr::swap(ptr.add(b), ptr.add(c)) };
+
+            let mut mid = 0;
+            for i in 0..c {
+                if less(is_less, i, c, ptr) {
+                    unsafe { ptr::swap(ptr.add(i), ptr.add(mid)) };
+                    mid += 1;
+                }
+            }
+            unsafe { ptr::swap(ptr.add(mid), ptr.add(c)) };
+
+            // recurse into the shorter side and loop on the longer one, so the stack depth
+            // stays logarithmic.
+            let right = unsafe { ptr.add(mid + 1) };
+            let right_len = len - mid - 1;
+            if mid < right_len {
+                unsafe { Self::introsort(ptr, mid, is_less, limit) };
+                ptr = right;
+                len = right_len;
+            } else {
+                unsafe { Self::introsort(right, right_len, is_less, limit) };
+                len = mid;
+            }
+        }
+    }
+
+    /// heapsorts the `len` elements at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `len` elements if `len > 1`.
+    unsafe fn heapsort<F: FnMut(&T, &T) -> bool>(ptr: *mut T, len: usize, is_less: &mut F) {
+        let mut sift_down = |end: usize, mut node: usize| loop {
+            let mut child = 2 * node + 1;
+            if child >= end {
+                break;
+            }
+            unsafe {
+                if child + 1 < end && is_less(&*ptr.add(child), &*ptr.add(child + 1)) {
+                    child += 1;
+                }
+                if !is_less(&*ptr.add(node), &*ptr.add(child)) {
+                    break;
+                }
+                ptr::swap(ptr.add(node), ptr.add(child));
+            }
+            node = child;
+        };
+
+        for node in (0..len / 2).rev() {
+            sift_down(len, node);
+        }
+        for end in (1..len).rev() {
+            unsafe { ptr::swap(ptr, ptr.add(end)) };
+            sift_down(end, 0);
+        }
+    }
+
+    /// returns whether `self` and `other` share at least one byte of memory. slices that
+    /// only touch at their ends don't overlap, and neither do empty slices.
+    pub fn overlaps(&self, other: &UncookedSlice<T>) -> bool {
+        let (len, other_len) = (self.len_bytes(), other.len_bytes());
+        if len == 0 || other_len == 0 {
+            return false;
+        }
+
+        let start = self.inner.addr();
+        let other_start = other.inner.addr();
+        start < other_start + other_len && other_start < start + len
+    }
+
+    /// returns the longest leading sub-slice whose elements all match `pred`.
+    ///
+    /// when `T: Copy`, `uncooked.take_while(pred)` resolves to [Iterator::take_while]
+    /// instead, so call this as `UncookedSlice::take_while(&uncooked, pred)`.
+    pub fn take_while<P: FnMut(&T) -> bool>(&self, mut pred: P) -> UncookedSlice<T> {
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<T>();
+        let run = (0..len)
+            .find(|&i| !pred(unsafe { &*ptr.add(i) }))
+            .unwrap_or(len);
+
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, run),
+        }
+    }
+
+    /// returns the sub-slice after the longest leading run of elements that match `pred`.
+    ///
+    /// when `T: Copy`, `uncooked.skip_while(pred)` resolves to [Iterator::skip_while]
+    /// instead, so call this as `UncookedSlice::skip_while(&uncooked, pred)`.
+    pub fn skip_while<P: FnMut(&T) -> bool>(&self, pred: P) -> UncookedSlice<T> {
+        let run = UncookedSlice::take_while(self, pred).inner.len();
+        unsafe { self.split_at_unchecked(run) }.1
+    }
+
+    /// fills the slice with values linearly interpolated from `start` at the first element
+    /// to `end` at the last element. a single element is set to `start`. see [Lerp] for how
+    /// integers are rounded.
+    pub fn fill_gradient(&mut self, start: T, end: T)
+    where
+        T: Lerp,
+    {
+        let len = self.inner.len();
+        let steps = len.saturating_sub(1);
+        let ptr = self.inner.cast::<T>();
+        for i in 0..len {
+            unsafe { *ptr.add(i) = T::lerp(start, end, i, steps) };
+        }
+    }
+
+    /// returns an iterator over non-overlapping mutable chunks of `chunk_size` elements,
+    /// starting at the end of the slice. the last chunk yielded, at the front of the slice,
+    /// is shorter if `chunk_size` doesn't divide the length. see [slice::rchunks_mut].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// [slice::rchunks_mut]: https://doc.rust-lang.org/std/primitive.slice.html#method.rchunks_mut
+    pub fn rchunks_mut(&mut self, chunk_size: usize) -> RChunksMut<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        RChunksMut {
+            slice: UncookedSlice { inner: self.inner },
+            chunk_size,
+        }
+    }
+
+    /// copies the first `min(len, src.len())` elements of `src` into `self`, and returns
+    /// the number of elements copied. the two slices may overlap.
+    pub fn copy_prefix_from(&mut self, src: &UncookedSlice<T>) -> usize
+    where
+        T: Copy,
+    {
+        let count = self.inner.len().min(src.inner.len());
+        if count == 0 {
+            return 0;
+        }
+
+        unsafe { ptr::copy(src.inner.cast::<T>(), self.inner.cast::<T>(), count) };
+        count
+    }
+
+    /// binary searches a slice sorted by the key `f` extracts from each element. returns
+    /// `Ok` with the index of a matching element, or `Err` with the index where an element
+    /// with that key could be inserted while keeping the slice sorted. see
+    /// [slice::binary_search_by_key].
+    ///
+    /// [slice::binary_search_by_key]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by_key
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(
+        &self,
+        key: &B,
+        f: F,
+    ) -> Result<usize, usize> {
+        if self.inner.is_empty() {
+            return Err(0);
+        }
+
+        unsafe { (*self.inner).binary_search_by_key(key, f) }
+    }
+
+    /// returns an iterator over mutable references to every `step`-th element, starting
+    /// with the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn iter_mut_step_by(&mut self, step: usize) -> IterMutStepBy<'_, T> {
+        assert!(step != 0, "step must be non-zero");
+        IterMutStepBy {
+            slice: UncookedSlice { inner: self.inner },
+            step,
+            _marker: PhantomData,
+        }
+    }
+
+    /// splits the slice into two at `mid`, or returns a [SplitError] describing the failure
+    /// if `mid > len`.
+    pub fn try_split_at(
+        &self,
+        mid: usize,
+    ) -> Result<(UncookedSlice<T>, UncookedSlice<T>), SplitError> {
+        let len = self.inner.len();
+        self.split_at_checked(mid).ok_or(SplitError { mid, len })
+    }
+
+    /// copies the elements of `self` into `dest` in reverse order, so that `self[len - 1 - i]`
+    /// ends up in `dest[i]`.
+    ///
+    /// the two slices must not overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn copy_reversed_into(&self, dest: &mut UncookedSlice<T>)
+    where
+        T: Copy,
+    {
+        let len = self.inner.len();
+        let dest_len = dest.inner.len();
+        if len != dest_len {
+            panic!(
+                "source slice length ({len}) does not match destination slice length ({dest_len})"
+            );
+        }
+
+        let src = self.inner.cast::<T>();
+        let dst = dest.inner.cast::<T>();
+        for i in 0..len {
+            unsafe { *dst.add(i) = *src.add(len - 1 - i) };
+        }
+    }
+
+    /// calls `f` on every overlapping window of `window` elements, in order, and writes the
+    /// results into successive slots of `dest`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero or greater than the length of `self`, or if the length
+    /// of `dest` isn't exactly `len - window + 1`.
+    pub fn windowed_reduce<F: FnMut(&UncookedSlice<T>) -> T>(
+        &self,
+        window: usize,
+        dest: &mut UncookedSlice<T>,
+        mut f: F,
+    ) {
+        assert!(window != 0, "window size must be non-zero");
+        let len = self.inner.len();
+        assert!(
+            window <= len,
+            "window size {window} is larger than slice of len {len}"
+        );
+        let count = len - window + 1;
+        let dest_len = dest.inner.len();
+        assert!(
+            dest_len == count,
+            "destination of len {dest_len} doesn't fit the {count} windows"
+        );
+
+        let src = self.inner.cast::<T>();
+        let dst = dest.inner.cast::<T>();
+        for i in 0..count {
+            let current = UncookedSlice {
+                inner: ptr::slice_from_raw_parts_mut(unsafe { src.add(i) }, window),
+            };
+            unsafe { *dst.add(i) = f(&current) };
+        }
+    }
+}
+
+impl<T> UncookedSlice<MaybeUninit<T>> {
+    /// reinterprets the slice as a slice of initialized values, keeping the length.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the slice must be initialized. See [MaybeUninit::assume_init].
+    pub const unsafe fn assume_init(self) -> UncookedSlice<T> {
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<T>();
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(ptr, len),
+        }
+    }
+}
+
+impl<T, const N: usize> UncookedSlice<[T; N]> {
+    /// reinterprets a slice of arrays as a flat slice of `len * N` elements, which can
+    /// still be written through. see [slice::as_flattened_mut].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len * N` overflows, which can only happen for zero-sized `T`.
+    ///
+    /// [slice::as_flattened_mut]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_flattened_mut
+    pub fn flatten_mut(self) -> UncookedSlice<T> {
+        let len = match self.inner.len().checked_mul(N) {
+            Some(len) => len,
+            None => panic!("slice len overflow"),
+        };
+        UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(self.inner.cast::<T>(), len),
+        }
+    }
+}
+
+impl UncookedSlice<u8> {
+    /// returns an iterator over the individual bits of the bytes, least significant bit
+    /// of each byte first. yields `len * 8` bits in total.
+    pub fn bits(&self) -> Bits<'_> {
+        Bits {
+            slice: UncookedSlice { inner: self.inner },
+            bit: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// returns the index of the first byte equal to `needle`, or `None` if there is none.
+    ///
+    /// the aligned middle of the slice is scanned a `usize` at a time, which is
+    /// considerably faster than checking byte by byte on large slices.
+    pub fn find_byte(&self, needle: u8) -> Option<usize> {
+        const WORD: usize = size_of::<usize>();
+        const LO: usize = usize::MAX / 0xFF;
+        const HI: usize = LO << 7;
+
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<u8>();
+        let find_in =
+            |start: usize, end: usize| (start..end).find(|&i| unsafe { *ptr.add(i) } == needle);
+
+        let head = ptr.align_offset(WORD).min(len);
+        if let Some(i) = find_in(0, head) {
+            return Some(i);
+        }
+
+        let broadcast = LO * needle as usize;
+        let mut i = head;
+        while i + WORD <= len {
+            let word = unsafe { *ptr.add(i).cast::<usize>() } ^ broadcast;
+            if word.wrapping_sub(LO) & !word & HI != 0 {
+                return find_in(i, i + WORD);
+            }
+            i += WORD;
+        }
+
+        find_in(i, len)
+    }
+
+    /// computes the Fletcher-32 checksum of the bytes. the bytes are read as little-endian
+    /// 16-bit words, with a zero byte appended if the length is odd.
+    pub fn fletcher32(&self) -> u32 {
+        // the largest number of words that can be summed before `c1` could overflow.
+        const BLOCK: usize = 360;
+
+        let len = self.inner.len();
+        let ptr = self.inner.cast::<u8>();
+        let words = len.div_ceil(2);
+        let word_at = |i: usize| {
+            let lo = unsafe { *ptr.add(2 * i) };
+            let hi = match 2 * i + 1 < len {
+                true => unsafe { *ptr.add(2 * i + 1) },
+                false => 0,
+            };
+            u16::from_le_bytes([lo, hi]) as u32
+        };
+
+        let (mut c0, mut c1) = (0u32, 0u32);
+        let mut start = 0;
+        while start < words {
+            let end = (start + BLOCK).min(words);
+            for i in start..end {
+                c0 += word_at(i);
+                c1 += c0;
+            }
+            c0 %= 0xFFFF;
+            c1 %= 0xFFFF;
+            start = end;
+        }
+
+        c1 << 16 | c0
+    }
+
+    /// swaps the bytes of every adjacent pair: `(0, 1)`, `(2, 3)`, and so on. this converts
+    /// a buffer of 16-bit values between little and big endian. if the length is odd, the
+    /// trailing byte is left as is.
+    pub fn swap_byte_pairs(&mut self) {
+        let ptr = self.inner.cast::<u8>();
+        for i in 0..self.inner.len() / 2 {
+            unsafe { ptr::swap(ptr.add(2 * i), ptr.add(2 * i + 1)) };
+        }
+    }
+
+    /// replaces every byte `b` with `lut[b]`.
+    pub fn apply_lut(&mut self, lut: &[u8; 256]) {
+        let ptr = self.inner.cast::<u8>();
+        for i in 0..self.inner.len() {
+            unsafe { *ptr.add(i) = lut[*ptr.add(i) as usize] };
+        }
+    }
+}
+
+impl<T> Index<usize> for UncookedSlice<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        unsafe { &*self.inner.cast::<T>().add(index) }
+    }
+}
+
+impl<T> IndexMut<usize> for UncookedSlice<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        unsafe { &mut *self.inner.cast::<T>().add(index) }
+    }
+}
+
+/// wrapper around [UncookedSlice] that bounds-checks indexing. see [UncookedSlice::checked].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash, Debug)]
+pub struct CheckedUncookedSlice<T> {
+    slice: UncookedSlice<T>,
+}
+
+impl<T> CheckedUncookedSlice<T> {
+    /// returns the wrapped [UncookedSlice].
+    pub const fn unchecked(self) -> UncookedSlice<T> {
+        self.slice
+    }
+}
+
+impl<T> Index<usize> for CheckedUncookedSlice<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        let len = self.slice.inner.len();
+        if index >= len {
+            panic!("index {index} out of bounds for slice of len {len}");
+        }
+        &self.slice[index]
+    }
+}
+
+impl<T> IndexMut<usize> for CheckedUncookedSlice<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.slice.inner.len();
+        if index >= len {
+            panic!("index {index} out of bounds for slice of len {len}");
+        }
+        &mut self.slice[index]
+    }
+}
+
+/// wrapper around [UncookedSlice] whose length is known to be exactly `N`. construct it
+/// from a mutable slice of length `N` through [TryFrom].
+///
+/// the lifetime ties it to the borrow it was created from, which is what makes the
+/// conversion safe. for the same reason, indexing is bounds-checked against `N`, and the
+/// wrapped [UncookedSlice] is only available through [FixedUncookedSlice::as_uncooked].
+#[derive(PartialEq, PartialOrd, Ord, Eq, Hash, Debug)]
+pub struct FixedUncookedSlice<'a, T, const N: usize> {
+    slice: UncookedSlice<T>,
+    _marker: PhantomData<&'a mut [T; N]>,
+}
+
+impl<'a, T, const N: usize> FixedUncookedSlice<'a, T, N> {
+    /// returns the length of the slice, which is always `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// returns whether the slice is empty, which is the case only if `N` is zero.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// returns a copy of the wrapped [UncookedSlice].
+    ///
+    /// # Safety
+    ///
+    /// The returned slice isn't tied to `'a` and its indexing isn't bounds-checked. You must
+    /// not use it after `'a` ends, index it out of bounds, or access an element through it
+    /// while a reference obtained through `self` to the same element is alive.
+    pub const unsafe fn as_uncooked(&self) -> UncookedSlice<T> {
+        UncookedSlice {
+            inner: self.slice.inner,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for FixedUncookedSlice<'a, T, N> {
+    type Error = LengthError;
+
+    fn try_from(slice: &'a mut [T]) -> Result<Self, LengthError> {
+        if slice.len() != N {
+            return Err(LengthError {
+                expected: N,
+                len: slice.len(),
+            });
+        }
+
+        Ok(FixedUncookedSlice {
+            slice: UncookedSlice { inner: slice },
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T, const N: usize> Index<usize> for FixedUncookedSlice<'_, T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        if index >= N {
+            panic!("index {index} out of bounds for slice of len {N}");
+        }
+        unsafe { &*self.slice.inner.cast::<T>().add(index) }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for FixedUncookedSlice<'_, T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= N {
+            panic!("index {index} out of bounds for slice of len {N}");
+        }
+        unsafe { &mut *self.slice.inner.cast::<T>().add(index) }
+    }
+}
+
+/// error returned when converting a slice whose length isn't the expected one. see
+/// [FixedUncookedSlice].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LengthError {
+    /// the length that was required.
+    pub expected: usize,
+    /// the length of the slice.
+    pub len: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected slice of len {}, got len {}",
+            self.expected, self.len
+        )
+    }
+}
+
+impl core::error::Error for LengthError {}
+
+/// error returned when splitting a slice at an index past its end. see
+/// [UncookedSlice::try_split_at].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SplitError {
+    /// the index the split was attempted at.
+    pub mid: usize,
+    /// the length of the slice.
+    pub len: usize,
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot split slice of len {} at {}", self.len, self.mid)
+    }
+}
+
+impl core::error::Error for SplitError {}
+
+impl<T> Deref for UncookedSlice<T> {
+    type Target = *mut [T];
+
+    fn deref(&self) -> &*mut [T] {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for UncookedSlice<T> {
+    fn deref_mut(&mut self) -> &mut *mut [T] {
+        &mut self.inner
+    }
+}
+
+impl<T: Copy> Iterator for UncookedSlice<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let old_len = match self.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let old_ptr = self.inner.cast::<T>();
+
+        let new_len = old_len - 1;
+        let new_ptr = unsafe { old_ptr.add(1) };
+        let new_slice = ptr::slice_from_raw_parts_mut(new_ptr, new_len);
+        self.inner = new_slice;
+
+        Some(unsafe { *old_ptr })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for UncookedSlice<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        let old_len = self.inner.len();
+        let ptr = self.inner.cast::<T>();
+
+        if n >= old_len {
+            self.inner = ptr::slice_from_raw_parts_mut(ptr, 0);
+            return None;
+        }
+
+        let new_len = old_len - n - 1;
+        self.inner = ptr::slice_from_raw_parts_mut(ptr, new_len);
+
+        Some(unsafe { *ptr.add(new_len) })
+    }
+}
+
+/// iterator over copies of the values of an [UncookedSlice]. see [UncookedSlice::iter_copied].
+#[derive(Clone, Debug)]
+pub struct Copied<T> {
+    slice: UncookedSlice<T>,
+}
+
+impl<T: Copy> Iterator for Copied<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.slice.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for Copied<T> {}
+
+/// iterator over adjacent pairs of elements of an [UncookedSlice]. see [UncookedSlice::pairs].
+#[derive(Debug)]
+pub struct Pairs<'a, T> {
+    slice: UncookedSlice<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<(&'a T, &'a T)> {
+        let old_len = self.slice.inner.len();
+        if old_len < 2 {
+            return None;
+        }
+
+        let old_ptr = self.slice.inner.cast::<T>();
+
+        let new_len = old_len - 2;
+        let new_ptr = unsafe { old_ptr.add(2) };
+        self.slice.inner = ptr::slice_from_raw_parts_mut(new_ptr, new_len);
+
+        Some(unsafe { (&*old_ptr, &*old_ptr.add(1)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len() / 2;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Pairs<'_, T> {}
+
+/// iterator over the elements of an [UncookedSlice] and their indices. see
+/// [UncookedSlice::enumerate].
+#[derive(Debug)]
+pub struct Enumerate<'a, T> {
+    slice: UncookedSlice<T>,
+    index: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Enumerate<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let old_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let old_ptr = self.slice.inner.cast::<T>();
+
+        let new_len = old_len - 1;
+        let new_ptr = unsafe { old_ptr.add(1) };
+        self.slice.inner = ptr::slice_from_raw_parts_mut(new_ptr, new_len);
+
+        let index = self.index;
+        self.index += 1;
+
+        Some((index, unsafe { &*old_ptr }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Enumerate<'_, T> {}
+
+/// iterator over the mutable sub-slices of an [UncookedSlice] separated by elements that
+/// match a predicate. see [UncookedSlice::split_mut].
+pub struct SplitMut<T, F> {
+    slice: UncookedSlice<T>,
+    pred: F,
+    finished: bool,
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for SplitMut<T, F> {
+    type Item = UncookedSlice<T>;
+
+    fn next(&mut self) -> Option<UncookedSlice<T>> {
+        if self.finished {
+            return None;
+        }
+
+        let len = self.slice.inner.len();
+        let ptr = self.slice.inner.cast::<T>();
+
+        match (0..len).find(|&i| (self.pred)(unsafe { &*ptr.add(i) })) {
+            Some(i) => {
+                let rest_ptr = unsafe { ptr.add(i + 1) };
+                self.slice.inner = ptr::slice_from_raw_parts_mut(rest_ptr, len - i - 1);
+                Some(UncookedSlice {
+                    inner: ptr::slice_from_raw_parts_mut(ptr, i),
+                })
+            }
+            None => {
+                self.finished = true;
+                Some(UncookedSlice {
+                    inner: self.slice.inner,
+                })
+            }
+        }
+    }
+}
+
+/// iterator over the bits of an [UncookedSlice] of bytes. see [UncookedSlice::bits].
+#[derive(Debug)]
+pub struct Bits<'a> {
+    slice: UncookedSlice<u8>,
+    bit: u32,
+    _marker: PhantomData<&'a u8>,
+}
+
+impl Iterator for Bits<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        let old_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let old_ptr = self.slice.inner.cast::<u8>();
+        let byte = unsafe { *old_ptr };
+        let bit = self.bit;
+
+        if bit == 7 {
+            let new_ptr = unsafe { old_ptr.add(1) };
+            self.slice.inner = ptr::slice_from_raw_parts_mut(new_ptr, old_len - 1);
+            self.bit = 0;
+        } else {
+            self.bit += 1;
+        }
+
+        Some((byte >> bit) & 1 == 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len() * 8 - self.bit as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+/// iterator over the sub-slices of an [UncookedSlice] separated by elements that match a
+/// predicate, starting from the end. see [UncookedSlice::rsplit].
+pub struct RSplit<T, F> {
+    slice: UncookedSlice<T>,
+    pred: F,
+    finished: bool,
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for RSplit<T, F> {
+    type Item = UncookedSlice<T>;
+
+    fn next(&mut self) -> Option<UncookedSlice<T>> {
+        if self.finished {
+            return None;
+        }
+
+        let len = self.slice.inner.len();
+        let ptr = self.slice.inner.cast::<T>();
+
+        match (0..len).rfind(|&i| (self.pred)(unsafe { &*ptr.add(i) })) {
+            Some(i) => {
+                let tail_ptr = unsafe { ptr.add(i + 1) };
+                self.slice.inner = ptr::slice_from_raw_parts_mut(ptr, i);
+                Some(UncookedSlice {
+                    inner: ptr::slice_from_raw_parts_mut(tail_ptr, len - i - 1),
+                })
+            }
+            None => {
+                self.finished = true;
+                Some(UncookedSlice {
+                    inner: self.slice.inner,
+                })
+            }
+        }
+    }
+}
+
+/// iterator over at most `n` sub-slices of an [UncookedSlice] separated by elements that
+/// match a predicate. see [UncookedSlice::splitn].
+pub struct SplitN<T, F> {
+    slice: UncookedSlice<T>,
+    pred: F,
+    count: usize,
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for SplitN<T, F> {
+    type Item = UncookedSlice<T>;
+
+    fn next(&mut self) -> Option<UncookedSlice<T>> {
+        match self.count {
+            0 => return None,
+            1 => {
+                self.count = 0;
+                return Some(UncookedSlice {
+                    inner: self.slice.inner,
+                });
+            }
+            _ => self.count -= 1,
+        }
+
+        let len = self.slice.inner.len();
+        let ptr = self.slice.inner.cast::<T>();
+
+        match (0..len).find(|&i| (self.pred)(unsafe { &*ptr.add(i) })) {
+            Some(i) => {
+                let rest_ptr = unsafe { ptr.add(i + 1) };
+                self.slice.inner = ptr::slice_from_raw_parts_mut(rest_ptr, len - i - 1);
+                Some(UncookedSlice {
+                    inner: ptr::slice_from_raw_parts_mut(ptr, i),
+                })
+            }
+            None => {
+                self.count = 0;
+                Some(UncookedSlice {
+                    inner: self.slice.inner,
+                })
+            }
+        }
+    }
+}
+
+struct DebugContents<'a, T> {
+    slice: UncookedSlice<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for DebugContents<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for i in 0..self.slice.inner.len() {
+            list.entry(&self.slice[i]);
+        }
+        list.finish()
+    }
+}
+
+/// iterator over non-overlapping mutable chunks of an [UncookedSlice]. see
+/// [UncookedSlice::chunks_mut].
+#[derive(Debug)]
+pub struct ChunksMut<T> {
+    slice: UncookedSlice<T>,
+    chunk_size: usize,
+}
+
+impl<T> Iterator for ChunksMut<T> {
+    type Item = UncookedSlice<T>;
+
+    fn next(&mut self) -> Option<UncookedSlice<T>> {
+        let old_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let old_ptr = self.slice.inner.cast::<T>();
+        let chunk_len = self.chunk_size.min(old_len);
+
+        let new_ptr = unsafe { old_ptr.add(chunk_len) };
+        self.slice.inner = ptr::slice_from_raw_parts_mut(new_ptr, old_len - chunk_len);
+
+        Some(UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(old_ptr, chunk_len),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len().div_ceil(self.chunk_size);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for ChunksMut<T> {}
+
+/// iterator over the leading elements of an [UncookedSlice] that match a predicate. see
+/// [UncookedSlice::iter_while].
+pub struct IterWhile<'a, T, P> {
+    slice: UncookedSlice<T>,
+    pred: P,
+    finished: bool,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<T, P> IterWhile<'_, T, P> {
+    /// returns the elements that haven't been yielded yet, starting with the first element
+    /// that didn't match if the iterator has stopped.
+    pub fn remainder(&self) -> UncookedSlice<T> {
+        UncookedSlice {
+            inner: self.slice.inner,
+        }
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> Iterator for IterWhile<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.finished {
+            return None;
+        }
+
+        let old_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let old_ptr = self.slice.inner.cast::<T>();
+        let item = unsafe { &*old_ptr };
+        if !(self.pred)(item) {
+            self.finished = true;
+            return None;
+        }
+
+        let new_ptr = unsafe { old_ptr.add(1) };
+        self.slice.inner = ptr::slice_from_raw_parts_mut(new_ptr, old_len - 1);
+
+        Some(item)
+    }
+}
+
+/// iterator over copies of the values of an [UncookedSlice] in reverse order. see
+/// [UncookedSlice::iter_rev_copied].
+#[derive(Clone, Debug)]
+pub struct RevCopied<T> {
+    slice: UncookedSlice<T>,
+}
+
+impl<T: Copy> Iterator for RevCopied<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let new_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len - 1,
+        };
+
+        let ptr = self.slice.inner.cast::<T>();
+        self.slice.inner = ptr::slice_from_raw_parts_mut(ptr, new_len);
+
+        Some(unsafe { *ptr.add(new_len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for RevCopied<T> {}
+
+/// linear interpolation between two values. see [UncookedSlice::fill_gradient].
+pub trait Lerp: Copy {
+    /// returns the value `step / steps` of the way from `start` to `end`. returns exactly
+    /// `start` when `step` is 0, and exactly `end` when `step == steps` and `steps` isn't 0.
+    ///
+    /// the integer implementations round towards `start`.
+    fn lerp(start: Self, end: Self, step: usize, steps: usize) -> Self;
+}
+
+macro_rules! impl_lerp_float {
+    ($($t:ty),*) => {$(
+        impl Lerp for $t {
+            fn lerp(start: $t, end: $t, step: usize, steps: usize) -> $t {
+                if step == 0 {
+                    return start;
+                }
+                if step == steps {
+                    return end;
+                }
+                start + (end - start) * (step as $t / steps as $t)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_lerp_int {
+    ($($t:ty),*) => {$(
+        impl Lerp for $t {
+            fn lerp(start: $t, end: $t, step: usize, steps: usize) -> $t {
+                if step == 0 {
+                    return start;
+                }
+                let diff = end as i128 - start as i128;
+                (start as i128 + diff * step as i128 / steps as i128) as $t
+            }
+        }
+    )*};
+}
+
+impl_lerp_float!(f32, f64);
+impl_lerp_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// iterator over non-overlapping mutable chunks of an [UncookedSlice], starting at the end.
+/// see [UncookedSlice::rchunks_mut].
+#[derive(Debug)]
+pub struct RChunksMut<T> {
+    slice: UncookedSlice<T>,
+    chunk_size: usize,
+}
+
+impl<T> Iterator for RChunksMut<T> {
+    type Item = UncookedSlice<T>;
+
+    fn next(&mut self) -> Option<UncookedSlice<T>> {
+        let old_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let ptr = self.slice.inner.cast::<T>();
+        let new_len = old_len - self.chunk_size.min(old_len);
+        self.slice.inner = ptr::slice_from_raw_parts_mut(ptr, new_len);
+
+        let chunk_ptr = unsafe { ptr.add(new_len) };
+        Some(UncookedSlice {
+            inner: ptr::slice_from_raw_parts_mut(chunk_ptr, old_len - new_len),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len().div_ceil(self.chunk_size);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for RChunksMut<T> {}
+
+/// iterator over mutable references to every `step`-th element of an [UncookedSlice]. see
+/// [UncookedSlice::iter_mut_step_by].
+#[derive(Debug)]
+pub struct IterMutStepBy<'a, T> {
+    slice: UncookedSlice<T>,
+    step: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMutStepBy<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let old_len = match self.slice.inner.len() {
+            0 => return None,
+            len => len,
+        };
+
+        let old_ptr = self.slice.inner.cast::<T>();
+        let step = self.step.min(old_len);
+
+        let new_ptr = unsafe { old_ptr.add(step) };
+        self.slice.inner = ptr::slice_from_raw_parts_mut(new_ptr, old_len - step);
+
+        Some(unsafe { &mut *old_ptr })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.inner.len().div_ceil(self.step);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMutStepBy<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iterable() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut buf = [0i32; 6];
+        for (i, item) in uncooked.enumerate() {
+            buf[i] = item;
+        }
+
+        let uncooked2 = unsafe { UncookedSlice::new(ptr) };
+        assert_eq!(uncooked, uncooked2);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn test_deref_raw_slice() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let len = uncooked.len();
+
+        assert_eq!(uncooked.inner.len(), len);
+    }
+
+    #[test]
+    #[allow(unconditional_panic)]
+    #[allow(clippy::out_of_bounds_indexing)]
+    fn test_index() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let _uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(data[0], 0);
+        assert_eq!(data[1], 1);
+        assert_eq!(data[2], 2);
+        assert_eq!(data[3], 3);
+        assert_eq!(data[4], 4);
+        assert_eq!(data[5], 5);
+
+        let catch_unwind = std::panic::catch_unwind(|| data[6]);
+        assert!(catch_unwind.is_err())
+    }
+
+    #[test]
+    #[allow(unconditional_panic)]
+    #[allow(clippy::out_of_bounds_indexing)]
+    fn test_index_write() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked[0] = 1;
+        assert_eq!(uncooked[0], 1);
+        uncooked[0] = uncooked[5];
+        assert_eq!(uncooked[0], 5);
+
+        let catch_unwind = std::panic::catch_unwind(|| data[6]);
+        assert!(catch_unwind.is_err())
+    }
+
+    #[test]
+    fn test_split_at_unchecked() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (left, right) = unsafe { uncooked.split_at_unchecked(2) };
+        let (expected_left, expected_right) = data.split_at_mut(2);
+
+        assert_eq!(left.inner(), &raw mut *expected_left);
+        assert_eq!(right.inner(), &raw mut *expected_right);
+    }
+
+    #[test]
+    fn test_iter_copied() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut buf = [0i32; 6];
+        for (i, item) in uncooked.iter_copied().enumerate() {
+            buf[i] = item;
+        }
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+
+        let mut buf = [0i32; 6];
+        for (i, item) in uncooked.iter_copied().enumerate() {
+            buf[i] = item;
+        }
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(uncooked.len(), 6);
+    }
+
+    #[test]
+    fn test_pairs() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut pairs = uncooked.pairs();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs.next(), Some((&0, &1)));
+        assert_eq!(pairs.next(), Some((&2, &3)));
+        assert_eq!(pairs.next(), Some((&4, &5)));
+        assert_eq!(pairs.next(), None);
+    }
+
+    #[test]
+    fn test_pairs_odd_len() {
+        let mut data = [0, 1, 2, 3, 4];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut pairs = uncooked.pairs();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs.next(), Some((&0, &1)));
+        assert_eq!(pairs.next(), Some((&2, &3)));
+        assert_eq!(pairs.next(), None);
+    }
+
+    #[test]
+    fn test_new_within_bytes() {
+        let mut data = [0u32, 1, 2, 3];
+        let ptr = &raw mut data[..];
+
+        let under = unsafe { UncookedSlice::new_within_bytes(ptr, 16) };
+        assert_eq!(under.map(UncookedSlice::inner), Some(ptr));
+
+        let over = unsafe { UncookedSlice::new_within_bytes(ptr, 15) };
+        assert!(over.is_none());
+
+        // never dereferenced, only the metadata is inspected.
+        let huge = ptr::slice_from_raw_parts_mut(ptr.cast::<u32>(), usize::MAX / 2);
+        let overflow = unsafe { UncookedSlice::new_within_bytes(huge, usize::MAX) };
+        assert!(overflow.is_none());
+    }
+
+    #[test]
+    fn test_map_in_place() {
+        let mut data = [1, 2, 3, 4];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.map_in_place(|x| *x *= *x);
+
+        assert_eq!(data, [1, 4, 9, 16]);
+    }
+
+    #[test]
+    fn test_read_write_at() {
+        let mut data = [0u8; 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        for i in 0..6 {
+            unsafe { uncooked.write_at(i, i as u8 * 10) };
+        }
+        for i in 0..6 {
+            assert_eq!(unsafe { uncooked.read_at(i) }, i as u8 * 10);
+        }
+    }
+
+    #[test]
+    fn test_as_uninit() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let uninit = uncooked.as_uninit();
+        assert_eq!(uninit.len(), 6);
+
+        let roundtrip = unsafe { uninit.assume_init() };
+        assert_eq!(roundtrip, uncooked);
+
+        let mut buf = [0i32; 6];
+        for (i, item) in roundtrip.iter_copied().enumerate() {
+            buf[i] = item;
+        }
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_checked_index() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut checked = unsafe { UncookedSlice::new(ptr) }.checked();
+
+        assert_eq!(checked[0], 0);
+        assert_eq!(checked[5], 5);
+        checked[5] = 10;
+        assert_eq!(checked[5], 10);
+
+        let catch_unwind = std::panic::catch_unwind(|| checked[6]);
+        let payload = catch_unwind.unwrap_err();
+        let message = payload.downcast_ref::<std::string::String>().unwrap();
+        assert_eq!(message, "index 6 out of bounds for slice of len 6");
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let mut data = [10, 11, 12, 13, 14, 15];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut enumerate = UncookedSlice::enumerate(&uncooked);
+        assert_eq!(enumerate.len(), 6);
+
+        let mut count = 0;
+        for (i, item) in &mut enumerate {
+            assert_eq!(i, count);
+            assert!(ptr::eq(item, &uncooked[i]));
+            assert_eq!(*item, 10 + i);
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        assert_eq!(enumerate.len(), 0);
+    }
+
+    #[test]
+    fn test_split_mut() {
+        let mut data = [1u8, 2, 0, 3, 0, 0, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut lens = [0usize; 4];
+        for (i, mut segment) in unsafe { uncooked.split_mut(|&x| x == 0) }.enumerate() {
+            lens[i] = segment.len();
+            segment.map_in_place(|x| *x += 10);
+        }
+
+        assert_eq!(lens, [2, 1, 0, 3]);
+        assert_eq!(data, [11, 12, 0, 13, 0, 0, 14, 15, 16]);
+    }
+
+    #[test]
+    fn test_try_into_array() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.try_into_array::<6>(), Some([0, 1, 2, 3, 4, 5]));
+        assert_eq!(uncooked.try_into_array::<5>(), None);
+        assert_eq!(uncooked.try_into_array::<7>(), None);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let empty = unsafe { UncookedSlice::new(null) };
+        assert_eq!(empty.try_into_array::<0>(), Some([]));
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut data = [0b0000_0001u8, 0b1000_0000];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut bits = uncooked.bits();
+        assert_eq!(bits.len(), 16);
+
+        let mut buf = [false; 16];
+        for (i, bit) in (&mut bits).enumerate() {
+            buf[i] = bit;
+        }
+
+        let mut expected = [false; 16];
+        expected[0] = true;
+        expected[15] = true;
+        assert_eq!(buf, expected);
+        assert_eq!(bits.next(), None);
+    }
+
+    #[test]
+    fn test_rsplit() {
+        let mut data = [1u8, 2, 0, 3, 0, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut rsplit = uncooked.rsplit(|&x| x == 0);
+        assert_eq!(rsplit.next().map(|s| s.len()), Some(3));
+        assert_eq!(rsplit.next().map(|s| s[0]), Some(3));
+        let first = rsplit.next().unwrap();
+        assert_eq!((first.len(), first[0], first[1]), (2, 1, 2));
+        assert!(rsplit.next().is_none());
+    }
+
+    #[test]
+    fn test_splitn() {
+        let mut data = [1u8, 2, 0, 3, 0, 4];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut splitn = uncooked.splitn(2, |&x| x == 0);
+        let head = splitn.next().unwrap();
+        assert_eq!((head.len(), head[0], head[1]), (2, 1, 2));
+        let tail = splitn.next().unwrap();
+        assert_eq!((tail.len(), tail[0], tail[1], tail[2]), (3, 3, 0, 4));
+        assert!(splitn.next().is_none());
+    }
+
+    #[test]
+    fn test_debug_contents() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let formatted = std::format!("{:?}", uncooked.debug_contents());
+        assert_eq!(formatted, "[0, 1, 2, 3, 4, 5]");
+    }
+
+    #[test]
+    fn test_copy_to_uninit() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut buf = [MaybeUninit::<i32>::uninit(); 6];
+        let buf_ptr = &raw mut buf[..];
+        let mut dest = unsafe { UncookedSlice::new(buf_ptr) };
+
+        uncooked.copy_to_uninit(&mut dest);
+
+        let init = unsafe { dest.assume_init() };
+        assert_eq!(init.try_into_array::<6>(), Some([0, 1, 2, 3, 4, 5]));
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let empty = unsafe { UncookedSlice::new(null) };
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<MaybeUninit<u32>>(), 0);
+        let mut empty_dest = unsafe { UncookedSlice::new(null) };
+        empty.copy_to_uninit(&mut empty_dest);
+    }
+
+    #[test]
+    fn test_index_back() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(*uncooked.index_back(0), 5);
+        assert_eq!(*uncooked.index_back(5), 0);
+        assert_eq!(uncooked.get_back(2), Some(&3));
+        assert_eq!(uncooked.get_back(6), None);
+        assert_eq!(uncooked.get_back(usize::MAX), None);
+
+        *uncooked.index_back_mut(1) = 10;
+        assert_eq!(uncooked[4], 10);
+
+        let catch_unwind = std::panic::catch_unwind(|| *uncooked.index_back(6));
+        assert!(catch_unwind.is_err());
+
+        let mut empty: [i32; 0] = [];
+        let empty = unsafe { UncookedSlice::new(&raw mut empty[..]) };
+        assert_eq!(empty.get_back(0), None);
+    }
+
+    #[test]
+    fn test_saturating_subslice() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let inside = uncooked.saturating_subslice(1, 3);
+        assert_eq!((inside.len(), inside[0], inside[2]), (3, 1, 3));
+
+        let partial = uncooked.saturating_subslice(4, 10);
+        assert_eq!((partial.len(), partial[0], partial[1]), (2, 4, 5));
+
+        let overflow = uncooked.saturating_subslice(4, usize::MAX);
+        assert_eq!(overflow.len(), 2);
+
+        let beyond = uncooked.saturating_subslice(10, 2);
+        assert_eq!(beyond.len(), 0);
+    }
+
+    #[test]
+    fn test_checked_cast() {
+        #[repr(align(4))]
+        struct Aligned([u8; 16]);
+
+        let mut data = Aligned([0; 16]);
+        data.0[4] = 1;
+        let ptr = &raw mut data.0[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let words = unsafe { uncooked.checked_cast::<u32>() }.unwrap();
+        assert_eq!(words.len(), 4);
+        assert_eq!(words[1], u32::from_ne_bytes([1, 0, 0, 0]));
+
+        let unaligned = uncooked.saturating_subslice(1, 4);
+        assert!(unsafe { unaligned.checked_cast::<u32>() }.is_none());
+
+        let odd = uncooked.saturating_subslice(0, 15);
+        assert!(unsafe { odd.checked_cast::<u32>() }.is_none());
+    }
+
+    #[test]
+    fn test_fill_range() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.fill_range(2..4, 9);
+        assert_eq!(data, [0, 1, 9, 9, 4, 5]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        let catch_unwind = std::panic::catch_unwind(move || uncooked.fill_range(4..7, 9));
+        assert!(catch_unwind.is_err());
+        assert_eq!(data, [0, 1, 9, 9, 4, 5]);
+    }
+
+    #[test]
+    fn test_advance_by() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.advance_by(2), Ok(()));
+        assert_eq!(uncooked.len(), 4);
+        assert_eq!(uncooked[0], 2);
+
+        assert_eq!(uncooked.advance_by(0), Ok(()));
+        assert_eq!(uncooked.len(), 4);
+
+        assert_eq!(uncooked.advance_by(7), Err(NonZero::new(3).unwrap()));
+        assert_eq!(uncooked.len(), 0);
+        assert_eq!(uncooked.next(), None);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        assert_eq!(uncooked.nth(3), Some(3));
+        assert_eq!(uncooked.next(), Some(4));
+        assert_eq!(uncooked.nth(1), None);
+    }
+
+    #[test]
+    fn test_chunks_mut() {
+        let mut data = [0, 1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let chunks = uncooked.chunks_mut(3);
+        assert_eq!(chunks.len(), 3);
+        for (i, mut chunk) in chunks.enumerate() {
+            chunk.map_in_place(|x| *x += 10 * i);
+        }
+
+        assert_eq!(data, [0, 1, 2, 13, 14, 15, 26]);
+    }
+
+    #[test]
+    fn test_deinterleave_into() {
+        extern crate std;
+        let mut data = [10, 20, 11, 21];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut left = [0; 2];
+        let mut right = [0; 2];
+        let mut channels = unsafe {
+            [
+                UncookedSlice::new(&raw mut left[..]),
+                UncookedSlice::new(&raw mut right[..]),
+            ]
+        };
+
+        uncooked.deinterleave_into(&mut channels);
+        assert_eq!(left, [10, 11]);
+        assert_eq!(right, [20, 21]);
+
+        uncooked.fill_range(0..4, 0);
+        uncooked.interleave_from(&channels);
+        assert_eq!(data, [10, 20, 11, 21]);
+
+        let catch_unwind =
+            std::panic::catch_unwind(move || uncooked.deinterleave_into(&mut channels[..1]));
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_position_of_ptr() {
+        let mut data = [0u32, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+        let base = ptr.cast::<u32>();
+
+        for i in 0..6 {
+            let element = unsafe { base.add(i) };
+            assert_eq!(uncooked.position_of_ptr(element), Some(i));
+        }
+
+        let past_end = unsafe { base.add(6) };
+        assert_eq!(uncooked.position_of_ptr(past_end), None);
+
+        let before = base.wrapping_sub(1);
+        assert_eq!(uncooked.position_of_ptr(before), None);
+
+        let misaligned = unsafe { base.byte_add(1) };
+        assert_eq!(uncooked.position_of_ptr(misaligned), None);
+    }
+
+    #[test]
+    fn test_split_array() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (head, rest) = uncooked.split_array::<2>();
+        assert_eq!(head, Some(&[0, 1]));
+        assert_eq!((rest.len(), rest[0]), (4, 2));
+
+        let (head, rest) = uncooked.split_array::<6>();
+        assert_eq!(head, Some(&[0, 1, 2, 3, 4, 5]));
+        assert_eq!(rest.len(), 0);
+
+        let (head, rest) = uncooked.split_array::<7>();
+        assert_eq!(head, None);
+        assert_eq!(rest, uncooked);
+
+        let (head, rest) = uncooked.split_array::<0>();
+        assert_eq!(head, Some(&[]));
+        assert_eq!(rest, uncooked);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let empty = unsafe { UncookedSlice::new(null) };
+        let (head, rest) = empty.split_array::<0>();
+        assert_eq!(head, Some(&[]));
+        assert_eq!(rest, empty);
+    }
+
+    #[test]
+    fn test_copy_from() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        let mut buf = [0; 6];
+        let buf_ptr = &raw mut buf[..];
+        let mut dest = unsafe { UncookedSlice::new(buf_ptr) };
+
+        unsafe { dest.copy_from(&uncooked) };
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+
+        let mut dest = unsafe { UncookedSlice::new(buf_ptr) };
+        let short = uncooked.saturating_subslice(0, 5);
+        let catch_unwind = std::panic::catch_unwind(move || unsafe { dest.copy_from(&short) });
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_borrow_for() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let borrowed = unsafe { uncooked.borrow_for(6) };
+        assert_eq!(borrowed, &[0, 1, 2, 3, 4, 5]);
+
+        let catch_unwind = std::panic::catch_unwind(|| unsafe { uncooked.borrow_for(5) });
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let mut data = [3, 1, 4, 1, 5, 9, 2, 6];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (min, max) = uncooked.min_max().unwrap();
+        assert_eq!((*min, *max), (1, 9));
+        assert!(ptr::eq(min, &uncooked[1]));
+
+        let mut single = [7];
+        let single = unsafe { UncookedSlice::new(&raw mut single[..]) };
+        let (min, max) = single.min_max().unwrap();
+        assert!(ptr::eq(min, max));
+
+        let mut empty: [i32; 0] = [];
+        let empty = unsafe { UncookedSlice::new(&raw mut empty[..]) };
+        assert_eq!(empty.min_max(), None);
+    }
+
+    #[test]
+    fn test_rotate_to_front() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.rotate_to_front(2);
+        assert_eq!(data, [2, 3, 4, 5, 0, 1]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        uncooked.rotate_to_front(6);
+        assert_eq!(data, [2, 3, 4, 5, 0, 1]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        let catch_unwind = std::panic::catch_unwind(move || uncooked.rotate_to_front(7));
+        assert!(catch_unwind.is_err());
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let mut empty = unsafe { UncookedSlice::new(null) };
+        empty.rotate_to_front(0);
+    }
+
+    #[test]
+    fn test_iter_while() {
+        let mut data = [2, 4, 6, 7, 8];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut iter = uncooked.iter_while(|x| x % 2 == 0);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let remainder = iter.remainder();
+        assert_eq!((remainder.len(), remainder[0], remainder[1]), (2, 7, 8));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_slice_range() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let range = uncooked.slice_range(1..4);
+        assert_eq!((range.len(), range[0]), (3, 1));
+
+        let inclusive = uncooked.slice_range(1..=4);
+        assert_eq!((inclusive.len(), inclusive[3]), (4, 4));
+
+        let to = uncooked.slice_range(..2);
+        assert_eq!((to.len(), to[1]), (2, 1));
+
+        let to_inclusive = uncooked.slice_range(..=5);
+        assert_eq!(to_inclusive, uncooked);
+
+        let from = uncooked.slice_range(3..);
+        assert_eq!((from.len(), from[0]), (3, 3));
+
+        let full = uncooked.slice_range(..);
+        assert_eq!(full, uncooked);
+
+        let empty = uncooked.slice_range(6..);
+        assert_eq!(empty.len(), 0);
+
+        let catch_unwind = std::panic::catch_unwind(|| uncooked.slice_range(..=6));
+        assert!(catch_unwind.is_err());
+
+        let catch_unwind = std::panic::catch_unwind(|| uncooked.slice_range(2..=usize::MAX));
+        assert!(catch_unwind.is_err());
+
+        let catch_unwind = std::panic::catch_unwind(|| uncooked.slice_range(4..2));
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_split_at_checked() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (left, right) = uncooked.split_at_checked(2).unwrap();
+        assert_eq!((left.len(), left[1]), (2, 1));
+        assert_eq!((right.len(), right[0]), (4, 2));
+
+        let (left, right) = uncooked.split_at_checked(6).unwrap();
+        assert_eq!((left.len(), right.len()), (6, 0));
+
+        assert!(uncooked.split_at_checked(7).is_none());
+    }
+
+    #[test]
+    fn test_find_byte() {
+        let mut data = [0u8; 67];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8 + 1;
+        }
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.find_byte(1), Some(0));
+        assert_eq!(uncooked.find_byte(3), Some(2));
+        assert_eq!(uncooked.find_byte(20), Some(19));
+        assert_eq!(uncooked.find_byte(66), Some(65));
+        assert_eq!(uncooked.find_byte(67), Some(66));
+        assert_eq!(uncooked.find_byte(0), None);
+        assert_eq!(uncooked.find_byte(0x80), None);
+
+        // every possible alignment of the start of the slice
+        for start in 0..size_of::<usize>() {
+            let sub = uncooked.saturating_subslice(start, 67);
+            assert_eq!(sub.find_byte(60), Some(59 - start));
+            assert_eq!(sub.find_byte(start as u8), None);
+        }
+    }
+
+    #[test]
+    fn test_split_off_front() {
+        let mut data = [0, 1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let front = uncooked.split_off_front(3);
+        assert_eq!((front.len(), front[0], front[2]), (3, 0, 2));
+        assert_eq!((uncooked.len(), uncooked[0]), (4, 3));
+
+        let front = uncooked.split_off_front(3);
+        assert_eq!((front.len(), front[0], front[2]), (3, 3, 5));
+        assert_eq!((uncooked.len(), uncooked[0]), (1, 6));
+
+        let front = uncooked.split_off_front(3);
+        assert_eq!((front.len(), front[0]), (1, 6));
+        assert_eq!(uncooked.len(), 0);
+
+        let front = uncooked.split_off_front(3);
+        assert_eq!(front.len(), 0);
+        assert_eq!(uncooked.len(), 0);
+    }
+
+    #[test]
+    fn test_write_iter() {
+        let mut data = [0; 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.write_iter([7, 8, 9]), 3);
+        assert_eq!(data, [7, 8, 9, 0, 0, 0]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        let mut source = 1..;
+        assert_eq!(uncooked.write_iter(&mut source), 6);
+        assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(source.next(), Some(7));
+    }
+
+    #[test]
+    fn test_align_offset() {
+        #[repr(align(8))]
+        struct Aligned([u8; 16]);
+
+        let mut data = Aligned([0; 16]);
+        let ptr = &raw mut data.0[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.align_offset(8), 0);
+        assert_eq!(uncooked.align_offset(1), 0);
+
+        let sub = uncooked.saturating_subslice(3, 8);
+        assert_eq!(sub.align_offset(8), 5);
+        assert_eq!(sub.align_offset(4), 1);
+        assert_eq!(sub.align_offset(1), 0);
+    }
+
+    #[test]
+    fn test_reverse_chunks() {
+        extern crate std;
+        let mut data = [1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.reverse_chunks(2);
+        assert_eq!(data, [5, 6, 3, 4, 1, 2]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        uncooked.reverse_chunks(3);
+        assert_eq!(data, [4, 1, 2, 5, 6, 3]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        let catch_unwind = std::panic::catch_unwind(move || uncooked.reverse_chunks(4));
+        assert!(catch_unwind.is_err());
+
+        let catch_unwind = std::panic::catch_unwind(move || uncooked.reverse_chunks(0));
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_new_debug_checked() {
+        extern crate std;
+        let mut data = [0u32, 1, 2, 3];
+        let ptr = &raw mut data[..];
+
+        let uncooked = unsafe { UncookedSlice::new_debug_checked(ptr) };
+        assert_eq!(uncooked.inner(), ptr);
+
+        let misaligned = unsafe { ptr.byte_add(1) };
+        let catch_unwind =
+            std::panic::catch_unwind(|| unsafe { UncookedSlice::new_debug_checked(misaligned) });
+        assert!(catch_unwind.is_err());
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let catch_unwind =
+            std::panic::catch_unwind(|| unsafe { UncookedSlice::new_debug_checked(null) });
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_prefix_sum_in_place() {
+        let mut data = [1, 2, 3, 4];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.prefix_sum_in_place();
+        assert_eq!(data, [1, 3, 6, 10]);
+
+        let mut single = [5];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut single[..]) };
+        uncooked.prefix_sum_in_place();
+        assert_eq!(single, [5]);
+
+        let mut empty: [i32; 0] = [];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut empty[..]) };
+        uncooked.prefix_sum_in_place();
+        assert_eq!(uncooked.len(), 0);
+    }
+
+    #[test]
+    fn test_chunk_window_count() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.chunk_count(3), 2);
+        assert_eq!(uncooked.chunk_count(4), 2);
+        assert_eq!(uncooked.chunk_count(10), 1);
+        assert_eq!(uncooked.chunk_count(0), 0);
+
+        assert_eq!(uncooked.window_count(1), 6);
+        assert_eq!(uncooked.window_count(4), 3);
+        assert_eq!(uncooked.window_count(6), 1);
+        assert_eq!(uncooked.window_count(7), 0);
+        assert_eq!(uncooked.window_count(0), 0);
+    }
+
+    #[test]
+    fn test_ptr_range_roundtrip() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let range = uncooked.as_mut_ptr_range();
+        assert_eq!(range.start, ptr.cast::<i32>());
+        assert_eq!(unsafe { range.end.offset_from(range.start) }, 6);
+
+        let roundtrip = unsafe { UncookedSlice::from_ptr_range(range) };
+        assert_eq!(roundtrip, uncooked);
+    }
+
+    #[test]
+    fn test_swap_ends() {
+        extern crate std;
+        let mut data = [1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.swap_ends(2);
+        assert_eq!(data, [5, 6, 3, 4, 1, 2]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        uncooked.swap_ends(3);
+        assert_eq!(data, [4, 1, 2, 5, 6, 3]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        let catch_unwind = std::panic::catch_unwind(move || uncooked.swap_ends(4));
+        assert!(catch_unwind.is_err());
+        assert_eq!(data, [4, 1, 2, 5, 6, 3]);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let mut empty = unsafe { UncookedSlice::new(null) };
+        empty.swap_ends(0);
+    }
+
+    #[test]
+    fn test_transpose_into() {
+        extern crate std;
+        let mut data = [1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut buf = [0; 6];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+
+        uncooked.transpose_into(&mut dest, 2, 3);
+        assert_eq!(buf, [1, 4, 2, 5, 3, 6]);
+
+        let catch_unwind =
+            std::panic::catch_unwind(move || uncooked.transpose_into(&mut dest, 2, 2));
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_iter_rev_copied() {
+        let mut data = [1, 2, 3];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut rev = uncooked.iter_rev_copied();
+        assert_eq!(rev.len(), 3);
+        assert_eq!(rev.next(), Some(3));
+        assert_eq!(rev.next(), Some(2));
+        assert_eq!(rev.next(), Some(1));
+        assert_eq!(rev.next(), None);
+
+        assert_eq!(uncooked.len(), 3);
+        assert_eq!(uncooked.try_into_array::<3>(), Some([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_fill_indexed() {
+        let mut data = [0u32; 4];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.fill_indexed(|i| i as u32 * 2);
+        assert_eq!(data, [0, 2, 4, 6]);
+
+        let mut buf = [MaybeUninit::<u32>::uninit(); 4];
+        let mut uninit = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+        uninit.fill_indexed(|i| MaybeUninit::new(i as u32 + 1));
+        let init = unsafe { uninit.assume_init() };
+        assert_eq!(init.try_into_array::<4>(), Some([1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_get_range() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let range = uncooked.get_range(1..4).unwrap();
+        assert_eq!((range.len(), range[0]), (3, 1));
+        assert_eq!(uncooked.get_range(..=5), Some(uncooked));
+        assert_eq!(uncooked.get_range(6..).map(|s| s.len()), Some(0));
+
+        assert_eq!(uncooked.get_range(2..7), None);
+        assert_eq!(uncooked.get_range(..=6), None);
+        assert_eq!(uncooked.get_range(2..=usize::MAX), None);
+        assert_eq!(uncooked.get_range(4..2), None);
     }
-}
 
-impl<T> IndexMut<usize> for UncookedSlice<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        unsafe { &mut *self.inner.cast::<T>().add(index) }
+    #[test]
+    fn test_dot() {
+        extern crate std;
+        let mut a = [1, 2, 3];
+        let mut b = [4, 5, 6];
+        let a = unsafe { UncookedSlice::new(&raw mut a[..]) };
+        let b = unsafe { UncookedSlice::new(&raw mut b[..]) };
+
+        assert_eq!(a.dot::<i32>(&b), 32);
+
+        let short = b.saturating_subslice(0, 2);
+        let catch_unwind = std::panic::catch_unwind(|| a.dot::<i32>(&short));
+        assert!(catch_unwind.is_err());
     }
-}
 
-impl<T> Deref for UncookedSlice<T> {
-    type Target = *mut [T];
+    #[test]
+    fn test_fixed_try_from() {
+        extern crate std;
+        let mut data = [0, 1, 2, 3];
 
-    fn deref(&self) -> &*mut [T] {
-        &self.inner
+        let mut fixed = FixedUncookedSlice::<_, 4>::try_from(&mut data[..]).unwrap();
+        assert_eq!(fixed.len(), 4);
+        fixed[0] = 10;
+        assert_eq!(fixed[0], 10);
+        assert_eq!(fixed[3], 3);
+
+        let uncooked = unsafe { fixed.as_uncooked() };
+        assert_eq!(uncooked.try_into_array::<4>(), Some([10, 1, 2, 3]));
+
+        let catch_unwind = std::panic::catch_unwind(|| fixed[4]);
+        assert!(catch_unwind.is_err());
+
+        let err = FixedUncookedSlice::<_, 3>::try_from(&mut data[..]).unwrap_err();
+        assert_eq!(
+            err,
+            LengthError {
+                expected: 3,
+                len: 4
+            }
+        );
     }
-}
 
-impl<T> DerefMut for UncookedSlice<T> {
-    fn deref_mut(&mut self) -> &mut *mut [T] {
-        &mut self.inner
+    #[test]
+    fn test_shift() {
+        let mut data = [1, 2, 3, 4];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.shift_left(1, 0);
+        assert_eq!(data, [2, 3, 4, 0]);
+
+        let mut data = [1, 2, 3, 4];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.shift_right(2, 0);
+        assert_eq!(data, [0, 0, 1, 2]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        uncooked.shift_left(10, 7);
+        assert_eq!(data, [7, 7, 7, 7]);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let mut empty = unsafe { UncookedSlice::new(null) };
+        empty.shift_left(1, 0);
+        empty.shift_right(1, 0);
     }
-}
 
-impl<T: Copy> Iterator for UncookedSlice<T> {
-    type Item = T;
+    #[test]
+    fn test_partition_copied() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
 
-    fn next(&mut self) -> Option<T> {
-        let old_len = match self.inner.len() {
-            0 => return None,
-            len => len,
+        let mut calls = 0;
+        let k = uncooked.partition_copied(|x| {
+            calls += 1;
+            x % 2 == 0
+        });
+
+        assert_eq!(k, 3);
+        assert_eq!(calls, 6);
+        assert!(data[..k].iter().all(|x| x % 2 == 0));
+        assert!(data[k..].iter().all(|x| x % 2 != 0));
+
+        let mut data = [2, 1, 1, 4, 3, 8, 8];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+        let mut calls = 0;
+        let k = uncooked.partition_copied(|x| {
+            calls += 1;
+            x % 2 == 0
+        });
+        assert_eq!((k, calls), (4, 7));
+        assert!(data[..k].iter().all(|x| x % 2 == 0));
+        assert!(data[k..].iter().all(|x| x % 2 != 0));
+    }
+
+    #[test]
+    fn test_fletcher32() {
+        let checksum = |bytes: &[u8]| {
+            let mut buf = [0u8; 8];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            let uncooked = unsafe { UncookedSlice::new(&raw mut buf[..bytes.len()]) };
+            uncooked.fletcher32()
         };
 
-        let old_ptr = self.inner.cast::<T>();
+        assert_eq!(checksum(b""), 0);
+        assert_eq!(checksum(b"abcde"), 0xF04F_C729);
+        assert_eq!(checksum(b"abcdef"), 0x5650_2D2A);
+        assert_eq!(checksum(b"abcdefgh"), 0xEBE1_9591);
 
-        let new_len = old_len - 1;
-        let new_ptr = unsafe { old_ptr.add(1) };
-        let new_slice = ptr::slice_from_raw_parts_mut(new_ptr, new_len);
-        self.inner = new_slice;
+        // long enough to need several blocks, checked against reducing after every word
+        let mut data = [0u8; 2001];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7) as u8 | 0x80;
+        }
+        let (mut c0, mut c1) = (0u32, 0u32);
+        for pair in data.chunks(2) {
+            let word = pair[0] as u32 | (*pair.get(1).unwrap_or(&0) as u32) << 8;
+            c0 = (c0 + word) % 0xFFFF;
+            c1 = (c1 + c0) % 0xFFFF;
+        }
+        let uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+        assert_eq!(uncooked.fletcher32(), c1 << 16 | c0);
+    }
 
-        Some(unsafe { *old_ptr })
+    #[test]
+    fn test_raw_parts_roundtrip() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (base, len) = uncooked.into_raw_parts();
+        assert_eq!(base, ptr.cast::<i32>());
+        assert_eq!(len, 6);
+
+        let roundtrip = unsafe { UncookedSlice::from_raw_parts(base, len) };
+        assert_eq!(roundtrip, uncooked);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_reduce() {
+        let mut data = [3, 1, 4, 1, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let max = UncookedSlice::reduce(&uncooked, |acc, &x| acc.max(x));
+        assert_eq!(max, Some(5));
+
+        let mut empty: [i32; 0] = [];
+        let empty = unsafe { UncookedSlice::new(&raw mut empty[..]) };
+        assert_eq!(UncookedSlice::reduce(&empty, |acc, &x| acc.max(x)), None);
+    }
 
     #[test]
-    fn test_iterable() {
-        let mut data = [0, 1, 2, 3, 4, 5];
+    fn test_swap_byte_pairs() {
+        let mut data = [0xAAu8, 0xBB, 0xCC, 0xDD];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        uncooked.swap_byte_pairs();
+        assert_eq!(data, [0xBB, 0xAA, 0xDD, 0xCC]);
+
+        let mut odd = [0xAAu8, 0xBB, 0xCC];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut odd[..]) };
+
+        uncooked.swap_byte_pairs();
+        assert_eq!(odd, [0xBB, 0xAA, 0xCC]);
+    }
+
+    #[test]
+    fn test_find_subslice() {
+        let mut data = [0, 1, 2, 3, 2, 3, 4];
         let ptr = &raw mut data[..];
         let uncooked = unsafe { UncookedSlice::new(ptr) };
 
+        assert_eq!(uncooked.find_subslice(&[2, 3]), Some(2));
+        assert_eq!(uncooked.find_subslice(&[3, 4]), Some(5));
+        assert_eq!(uncooked.find_subslice(&[0, 1]), Some(0));
+        assert_eq!(uncooked.find_subslice(&[2, 4]), None);
+        assert_eq!(uncooked.find_subslice(&[0; 8]), None);
+        assert_eq!(uncooked.find_subslice(&[]), Some(0));
+    }
+
+    #[test]
+    fn test_with_len() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let prefix = unsafe { UncookedSlice::new(ptr).with_len(3) };
+
+        assert_eq!(prefix.len(), 3);
+        assert_eq!(prefix.try_into_array::<3>(), Some([0, 1, 2]));
+
+        let extended = unsafe { prefix.with_len(6) };
         let mut buf = [0i32; 6];
-        for (i, item) in uncooked.enumerate() {
+        for (i, item) in extended.enumerate() {
             buf[i] = item;
         }
+        assert_eq!(buf, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(extended.inner(), ptr);
+    }
 
-        let uncooked2 = unsafe { UncookedSlice::new(ptr) };
-        assert_eq!(uncooked, uncooked2);
-        assert_eq!(buf, data);
+    #[test]
+    fn test_split_in_half_mut() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (mut front, mut back) = uncooked.split_in_half_mut().unwrap();
+        assert_eq!((front.len(), back.len()), (3, 3));
+        front.map_in_place(|x| *x += 10);
+        back.map_in_place(|x| *x += 20);
+        assert_eq!(data, [10, 11, 12, 23, 24, 25]);
+
+        let mut odd = [0, 1, 2];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut odd[..]) };
+        assert!(uncooked.split_in_half_mut().is_none());
     }
 
     #[test]
-    fn test_deref_raw_slice() {
+    fn test_apply_lut() {
+        let mut lut = [0u8; 256];
+        for (i, entry) in lut.iter_mut().enumerate() {
+            *entry = 255 - i as u8;
+        }
+
+        let mut data = [0u8, 1, 127, 128, 254, 255];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        uncooked.apply_lut(&lut);
+        assert_eq!(data, [255, 254, 128, 127, 1, 0]);
+    }
+
+    #[test]
+    fn test_saturating_index() {
         let mut data = [0, 1, 2, 3, 4, 5];
         let ptr = &raw mut data[..];
         let uncooked = unsafe { UncookedSlice::new(ptr) };
 
-        let len = uncooked.len();
+        assert_eq!(uncooked.saturating_index(2), Some(&2));
+        assert_eq!(uncooked.saturating_index(6), Some(&5));
+        assert_eq!(uncooked.saturating_index(usize::MAX), Some(&5));
 
-        assert_eq!(uncooked.inner.len(), len);
+        let mut empty: [i32; 0] = [];
+        let empty = unsafe { UncookedSlice::new(&raw mut empty[..]) };
+        assert_eq!(empty.saturating_index(0), None);
     }
 
     #[test]
-    #[allow(unconditional_panic)]
-    #[allow(clippy::out_of_bounds_indexing)]
-    fn test_index() {
+    fn test_count_matching() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.count_matching(|x| x % 2 == 0), 3);
+        assert_eq!(uncooked.count_matching(|&x| x > 6), 0);
+        assert_eq!(uncooked.len(), 6);
+    }
+
+    #[test]
+    fn test_copy_strided_into() {
         extern crate std;
+        let mut data = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut buf = [0; 3];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+
+        uncooked.copy_strided_into(&mut dest, 1, 3);
+        assert_eq!(buf, [1, 4, 7]);
+
+        let catch_unwind =
+            std::panic::catch_unwind(move || uncooked.copy_strided_into(&mut dest, 3, 3));
+        assert!(catch_unwind.is_err());
+
+        let catch_unwind =
+            std::panic::catch_unwind(move || uncooked.copy_strided_into(&mut dest, 0, 0));
+        assert!(catch_unwind.is_err());
+        assert_eq!(buf, [1, 4, 7]);
+    }
+
+    #[test]
+    fn test_equal_range() {
+        let mut data = [1, 2, 4, 4, 4, 7, 9];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.equal_range(&4), 2..5);
+        assert_eq!(uncooked.equal_range(&1), 0..1);
+        assert_eq!(uncooked.equal_range(&9), 6..7);
+        assert_eq!(uncooked.equal_range(&5), 5..5);
+        assert_eq!(uncooked.equal_range(&0), 0..0);
+        assert_eq!(uncooked.equal_range(&10), 7..7);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let empty = unsafe { UncookedSlice::new(null) };
+        assert_eq!(empty.equal_range(&4), 0..0);
+    }
+
+    #[test]
+    fn test_sort_unstable() {
+        let mut data = [5, 2, 9, 0, 7, 3, 8, 1, 6, 4];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+        uncooked.sort_unstable();
+        assert_eq!(data, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut sorted = [0, 1, 2, 3, 4];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut sorted[..]) };
+        uncooked.sort_unstable();
+        assert_eq!(sorted, [0, 1, 2, 3, 4]);
+
+        let mut duplicates = [3, 1, 3, 2, 1, 3];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut duplicates[..]) };
+        uncooked.sort_unstable();
+        assert_eq!(duplicates, [1, 1, 2, 3, 3, 3]);
+
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut duplicates[..]) };
+        uncooked.sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(duplicates, [3, 3, 3, 2, 1, 1]);
+
+        let mut long: [u32; 100] = core::array::from_fn(|i| (i as u32 * 37) % 41);
+        let mut expected = long;
+        expected.sort_unstable();
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut long[..]) };
+        uncooked.sort_unstable();
+        assert_eq!(long, expected);
+
+        let mut long: [u32; 100] = core::array::from_fn(|i| (i as u32 * 37) % 41);
+        unsafe { UncookedSlice::heapsort(long.as_mut_ptr(), long.len(), &mut |a, b| a < b) };
+        assert_eq!(long, expected);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let mut empty = unsafe { UncookedSlice::new(null) };
+        empty.sort_unstable();
+        empty.sort_unstable_by(|a, b| b.cmp(a));
+    }
+
+    #[test]
+    fn test_overlaps() {
         let mut data = [0, 1, 2, 3, 4, 5];
         let ptr = &raw mut data[..];
         let uncooked = unsafe { UncookedSlice::new(ptr) };
 
-        assert_eq!(data[0], 0);
-        assert_eq!(data[1], 1);
-        assert_eq!(data[2], 2);
-        assert_eq!(data[3], 3);
-        assert_eq!(data[4], 4);
-        assert_eq!(data[5], 5);
+        let mut other = [0, 1, 2];
+        let other = unsafe { UncookedSlice::new(&raw mut other[..]) };
+        assert!(!uncooked.overlaps(&other));
 
-        let catch_unwind = std::panic::catch_unwind(|| data[6]);
-        assert!(catch_unwind.is_err())
+        let (left, right) = uncooked.split_at_checked(3).unwrap();
+        assert!(!left.overlaps(&right));
+        assert!(!right.overlaps(&left));
+
+        let middle = uncooked.saturating_subslice(2, 2);
+        assert!(middle.overlaps(&left));
+        assert!(middle.overlaps(&right));
+        assert!(uncooked.overlaps(&middle));
+        assert!(middle.overlaps(&middle));
+
+        let empty = uncooked.saturating_subslice(3, 0);
+        assert!(!empty.overlaps(&uncooked));
     }
 
     #[test]
-    #[allow(unconditional_panic)]
-    #[allow(clippy::out_of_bounds_indexing)]
-    fn test_index_write() {
+    fn test_take_skip_while() {
+        let mut data = [2, 4, 6, 7, 8];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let taken = UncookedSlice::take_while(&uncooked, |x| x % 2 == 0);
+        assert_eq!(taken.try_into_array::<3>(), Some([2, 4, 6]));
+
+        let skipped = UncookedSlice::skip_while(&uncooked, |x| x % 2 == 0);
+        assert_eq!(skipped.try_into_array::<2>(), Some([7, 8]));
+
+        let all = UncookedSlice::take_while(&uncooked, |_| true);
+        assert_eq!(all, uncooked);
+        let none = UncookedSlice::skip_while(&uncooked, |_| true);
+        assert_eq!(none.len(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_fill_gradient() {
+        let mut data = [9.0f32; 5];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        uncooked.fill_gradient(0.0, 1.0);
+        assert_eq!(data[0], 0.0);
+        assert_eq!(data[2], 0.5);
+        assert_eq!(data[4], 1.0);
+
+        let mut ints = [0u8; 4];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut ints[..]) };
+        uncooked.fill_gradient(10, 0);
+        assert_eq!(ints, [10, 7, 4, 0]);
+
+        let mut single = [0i32];
+        let mut uncooked = unsafe { UncookedSlice::new(&raw mut single[..]) };
+        uncooked.fill_gradient(3, 8);
+        assert_eq!(single, [3]);
+    }
+
+    #[test]
+    fn test_rchunks_mut() {
+        let mut data = [0, 1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let chunks = uncooked.rchunks_mut(3);
+        assert_eq!(chunks.len(), 3);
+
+        let mut lens = [0; 3];
+        for (i, mut chunk) in chunks.enumerate() {
+            lens[i] = chunk.len();
+            chunk.map_in_place(|x| *x += 10 * i);
+        }
+
+        assert_eq!(lens, [3, 3, 1]);
+        assert_eq!(data, [20, 11, 12, 13, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_copy_prefix_from() {
+        let mut data = [1, 2, 3, 4];
+        let src = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        let mut buf = [0; 6];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+        assert_eq!(dest.copy_prefix_from(&src), 4);
+        assert_eq!(buf, [1, 2, 3, 4, 0, 0]);
+
+        let mut buf = [0; 2];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+        assert_eq!(dest.copy_prefix_from(&src), 2);
+        assert_eq!(buf, [1, 2]);
+
+        let mut buf = [0; 4];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+        assert_eq!(dest.copy_prefix_from(&src), 4);
+        assert_eq!(buf, [1, 2, 3, 4]);
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<u32>(), 0);
+        let mut empty = unsafe { UncookedSlice::new(null) };
+        assert_eq!(empty.copy_prefix_from(&src), 0);
+        assert_eq!(dest.copy_prefix_from(&empty), 0);
+    }
+
+    #[test]
+    fn test_flatten_mut() {
+        let mut data = [[1, 2], [3, 4]];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let mut flat = uncooked.flatten_mut();
+        assert_eq!(flat.len(), 4);
+        assert_eq!(flat[2], 3);
+
+        flat[1] = 20;
+        flat[3] = 40;
+        assert_eq!(data, [[1, 20], [3, 40]]);
+    }
+
+    #[test]
+    fn test_binary_search_by_key() {
+        let mut data = [(1, 'a'), (3, 'b'), (4, 'c'), (8, 'd')];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        assert_eq!(uncooked.binary_search_by_key(&4, |&(id, _)| id), Ok(2));
+        assert_eq!(uncooked.binary_search_by_key(&1, |&(id, _)| id), Ok(0));
+        assert_eq!(uncooked.binary_search_by_key(&5, |&(id, _)| id), Err(3));
+        assert_eq!(uncooked.binary_search_by_key(&9, |&(id, _)| id), Err(4));
+
+        let null = ptr::slice_from_raw_parts_mut(ptr::null_mut::<(u32, char)>(), 0);
+        let empty = unsafe { UncookedSlice::new(null) };
+        assert_eq!(empty.binary_search_by_key(&4, |&(id, _)| id), Err(0));
+    }
+
+    #[test]
+    fn test_iter_mut_step_by() {
+        let mut data = [1, 2, 3, 4, 5, 6];
+        let ptr = &raw mut data[..];
+        let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let iter = uncooked.iter_mut_step_by(2);
+        assert_eq!(iter.len(), 3);
+        for item in iter {
+            *item *= 2;
+        }
+
+        assert_eq!(data, [2, 2, 6, 4, 10, 6]);
+    }
+
+    #[test]
+    fn test_try_split_at() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        let (left, right) = uncooked.try_split_at(4).unwrap();
+        assert_eq!((left.len(), right.len(), right[0]), (4, 2, 4));
+
+        let err = uncooked.try_split_at(7).unwrap_err();
+        assert_eq!(err, SplitError { mid: 7, len: 6 });
+    }
+
+    #[test]
+    fn test_copy_reversed_into() {
         extern crate std;
+        let mut data = [1, 2, 3, 4];
+        let uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        let mut buf = [0; 4];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+
+        uncooked.copy_reversed_into(&mut dest);
+        assert_eq!(buf, [4, 3, 2, 1]);
+
+        let mut short = dest.saturating_subslice(0, 3);
+        let catch_unwind =
+            std::panic::catch_unwind(move || uncooked.copy_reversed_into(&mut short));
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_nth_back() {
         let mut data = [0, 1, 2, 3, 4, 5];
         let ptr = &raw mut data[..];
         let mut uncooked = unsafe { UncookedSlice::new(ptr) };
+        let mut expected = data.iter().copied();
 
-        uncooked[0] = 1;
-        assert_eq!(uncooked[0], 1);
-        uncooked[0] = uncooked[5];
-        assert_eq!(uncooked[0], 5);
+        assert_eq!(uncooked.nth_back(2), expected.nth_back(2));
+        assert_eq!(uncooked.len(), 3);
+        assert_eq!(uncooked.next_back(), expected.next_back());
+        assert_eq!(uncooked.next(), expected.next());
+        assert_eq!(uncooked.nth_back(5), expected.nth_back(5));
+        assert_eq!(uncooked.len(), 0);
+        assert_eq!(uncooked.next_back(), None);
+    }
 
-        let catch_unwind = std::panic::catch_unwind(|| data[6]);
-        assert!(catch_unwind.is_err())
+    #[test]
+    fn test_windowed_reduce() {
+        extern crate std;
+        let mut data = [1, 2, 3, 4];
+        let uncooked = unsafe { UncookedSlice::new(&raw mut data[..]) };
+
+        let mut buf = [0; 3];
+        let mut dest = unsafe { UncookedSlice::new(&raw mut buf[..]) };
+
+        uncooked.windowed_reduce(2, &mut dest, |window| window.iter_copied().sum());
+        assert_eq!(buf, [3, 5, 7]);
+
+        let catch_unwind =
+            std::panic::catch_unwind(move || uncooked.windowed_reduce(3, &mut dest, |w| w[0]));
+        assert!(catch_unwind.is_err());
+    }
+
+    #[test]
+    fn test_copy_to_uninit_overlapping() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let ptr = &raw mut data[..];
+        let uncooked = unsafe { UncookedSlice::new(ptr) };
+
+        uncooked.copy_to_uninit(&mut uncooked.as_uninit());
+        assert_eq!(data, [0, 1, 2, 3, 4, 5]);
+
+        let src = uncooked.saturating_subslice(0, 4);
+        let mut dest = uncooked.saturating_subslice(2, 4).as_uninit();
+        src.copy_to_uninit(&mut dest);
+        assert_eq!(data, [0, 1, 0, 1, 2, 3]);
     }
 }